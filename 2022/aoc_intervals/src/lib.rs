@@ -0,0 +1,280 @@
+// Shared helpers for puzzles that deal with (potentially huge) ranges of integers.
+// Instead of materializing every integer in a range, everything here only ever
+// looks at the two endpoints, so all operations are independent of the range width.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+// An inclusive interval of integers, i.e. `start..=end`.
+// The constructor guarantees `start <= end`, so an Interval is never empty.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    // Create the interval `start..=end`.
+    // Panics if `start > end`, since that would be an empty interval.
+    pub fn new(start: i64, end: i64) -> Interval {
+        if start > end {
+            panic!("Invalid interval {start}-{end}: start lies after end");
+        }
+        Interval { start, end }
+    }
+
+    // Create the interval consisting of a single value.
+    pub fn single(value: i64) -> Interval {
+        Interval { start: value, end: value }
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    // The number of integers contained in the interval.
+    pub fn len(&self) -> u64 {
+        self.end.abs_diff(self.start) + 1
+    }
+
+    // Intervals always contain at least one element, this only exists for API symmetry with `len`.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    // Check whether the given value lies within the interval.
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    // Check whether `other` is fully contained in this interval.
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    // Check whether the two intervals share at least one value.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // Check whether the two intervals overlap or directly border each other,
+    // e.g. 1-3 and 4-6. Such intervals can be merged into one without gaps.
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+
+    // Return the values common to both intervals, if any.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval::new(self.start.max(other.start), self.end.min(other.end)))
+        } else {
+            None
+        }
+    }
+
+    // Merge the two intervals into one, as long as they overlap or touch.
+    pub fn merge(&self, other: &Interval) -> Option<Interval> {
+        if self.touches(other) {
+            Some(Interval::new(self.start.min(other.start), self.end.max(other.end)))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A set of integers, stored as the minimal list of disjoint intervals.
+// The intervals are kept sorted and neither overlap nor touch each other.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    // The disjoint intervals making up the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // The total number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(|i| i.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Add an interval to the set, merging it with all intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        // Everything before `lo` ends too early to touch the new interval,
+        // everything from `hi` onwards starts too late.
+        let lo = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let hi = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+        // Collapse the touched intervals [lo, hi) and the new one into a single interval.
+        let merged = self.intervals[lo..hi]
+            .iter()
+            .fold(interval, |acc, i| acc.merge(i).unwrap());
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    // Check whether the given value is part of the set.
+    pub fn contains(&self, value: i64) -> bool {
+        self.find(value).is_ok()
+    }
+
+    // Check whether the interval is fully covered by the set.
+    pub fn contains_interval(&self, interval: &Interval) -> bool {
+        match self.find(interval.start) {
+            Ok(idx) => self.intervals[idx].contains_interval(interval),
+            Err(_) => false,
+        }
+    }
+
+    // Check whether the interval shares at least one value with the set.
+    pub fn overlaps(&self, interval: &Interval) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end < interval.start);
+        self.intervals
+            .get(idx)
+            .is_some_and(|i| i.overlaps(interval))
+    }
+
+    // All values that are in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    // All values that are in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        // Since both lists are sorted, a single merge-like sweep suffices.
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            if let Some(common) = x.intersection(&y) {
+                intervals.push(common);
+            }
+            // Advance whichever interval ends first, it can't overlap anything else.
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    // All the values within `bounds` that are *not* part of the set, as intervals.
+    pub fn gaps(&self, bounds: &Interval) -> Vec<Interval> {
+        let mut gaps = Vec::new();
+        let mut next = bounds.start;
+        for interval in &self.intervals {
+            if interval.end < bounds.start {
+                continue;
+            }
+            if interval.start > bounds.end {
+                break;
+            }
+            if interval.start > next {
+                gaps.push(Interval::new(next, interval.start - 1));
+            }
+            // Once an interval reaches up to i64::MAX there's nothing left to find.
+            match interval.end.checked_add(1) {
+                Some(n) => next = next.max(n),
+                None => return gaps,
+            }
+        }
+        if next <= bounds.end {
+            gaps.push(Interval::new(next, bounds.end));
+        }
+        gaps
+    }
+
+    // Locate the interval containing `value`.
+    // Returns `Ok(index)` if found, or `Err(index)` with the position the value would be inserted at.
+    fn find(&self, value: i64) -> Result<usize, usize> {
+        self.intervals.binary_search_by(|i| {
+            if i.end < value {
+                Ordering::Less
+            } else if i.start > value {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.intervals.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        write!(f, "{{{}}}", parts.join(", "))
+    }
+}
+
+// Sweep over the given intervals and determine how many of them cover each value.
+// Returns the maximal segments of constant, non-zero coverage in ascending order,
+// together with the number of intervals covering them.
+// Runs in O(n log n) for n intervals, independent of how wide they are.
+pub fn coverage(intervals: &[Interval]) -> Vec<(Interval, usize)> {
+    // Every interval opens at its start and closes right after its end.
+    // An interval reaching up to i64::MAX simply never closes.
+    let mut events: Vec<(i64, isize)> = Vec::with_capacity(intervals.len() * 2);
+    for i in intervals {
+        events.push((i.start, 1));
+        if let Some(after) = i.end.checked_add(1) {
+            events.push((after, -1));
+        }
+    }
+    events.sort_unstable();
+
+    let mut segments: Vec<(Interval, usize)> = Vec::new();
+    let mut depth: isize = 0;
+    let mut idx = 0;
+    while idx < events.len() {
+        // Apply all events at the current position at once.
+        let pos = events[idx].0;
+        while idx < events.len() && events[idx].0 == pos {
+            depth += events[idx].1;
+            idx += 1;
+        }
+        if depth == 0 {
+            continue;
+        }
+        // The coverage stays constant until the next event, or forever if there is none.
+        let end = events.get(idx).map_or(i64::MAX, |e| e.0 - 1);
+        segments.push((Interval::new(pos, end), depth as usize));
+    }
+    segments
+}
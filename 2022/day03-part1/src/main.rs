@@ -1,36 +1,110 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
+
+// Calculate the priority of an item. Only the letters a-z and A-Z are valid items.
+fn priority(item: char) -> Option<u32> {
+    match item {
+        item if item.is_ascii_lowercase() => Some((item as u32) - ('a' as u32) + 1),
+        item if item.is_ascii_uppercase() => Some((item as u32) - ('A' as u32) + 27),
+        _ => None,
+    }
+}
+
+// Describe an item and its priority for the report.
+fn describe(item: char) -> String {
+    match priority(item) {
+        Some(p) => format!("{item} ({p})"),
+        None => format!("{item:?} (invalid)"),
+    }
+}
+
+// Determine all items that appear in both compartments of a rucksack.
+// A BTreeSet keeps the result sorted, which makes the report deterministic.
+fn shared_items(line: &str) -> BTreeSet<char> {
+    // Split the string into two strings, exactly in the middle. Count characters rather
+    // than bytes, so lines with non-ASCII characters don't end up split inside one.
+    let middle = line
+        .char_indices()
+        .nth(line.chars().count() / 2)
+        .map_or(line.len(), |(i, _)| i);
+    let (left, right) = line.split_at(middle);
+    // Turn the left and right strings into sets.
+    let left: BTreeSet<char> = left.chars().collect();
+    let right: BTreeSet<char> = right.chars().collect();
+    // Use set intersection to determine the items that are in both compartments.
+    left.intersection(&right).copied().collect()
+}
 
 fn main() {
     // Use command line arguments to specify the input filename.
     let args: Vec<String> = std::env::args().collect();
     if args.len() < 2 {
-        panic!("Usage: ./main <input-file>\nNo input file provided. Exiting.");
+        panic!("Usage: ./main <input-file> [--report]\nNo input file provided. Exiting.");
     }
+    // With --report, list the shared items of every rucksack instead of only summing them up.
+    let report = args.iter().skip(2).any(|a| a == "--report");
 
     // Next, read the contents of the input file into a string for easier processing.
     let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
     // Line-by-line processing is easiest.
-    let mut input = input.lines();
+    let input = input.lines();
 
     // --- TASK BEGIN ---
     let mut total = 0;
-    while let Some(line) = input.next() {
-        // Split the string into two strings, exactly in the middle.
-        let (left, right) = line.split_at(line.as_bytes().len() / 2);
-        // Turn the left and right strings into HashSets.
-        let left: HashSet<char> = left.chars().collect();
-        let right: HashSet<char> = right.chars().collect();
-        // Use set intersection to determine the item that's in both compartments.
-        let item = *left.intersection(&right).next().unwrap();
-        // Calculate the priority.
-        let priority = match item {
-            item if item.is_ascii_lowercase() => (item as u32) - ('a' as u32) + 1,
-            item if item.is_ascii_uppercase() => (item as u32) - ('A' as u32) + 27,
-            _ => {panic!("Character out of range")}
-        };
+    let mut flagged = 0;
+    for (index, line) in input.enumerate() {
+        let items = shared_items(line);
+
+        if report {
+            // Describe the rucksack and flag everything that isn't exactly one shared item.
+            let listing = items
+                .iter()
+                .map(|&i| describe(i))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let flag = match items.len() {
+                1 => "",
+                0 => "  <-- no shared item",
+                _ => "  <-- several shared items",
+            };
+            let odd = if line.chars().count() % 2 == 1 { "  <-- odd length" } else { "" };
+            let non_ascii = if line.is_ascii() { "" } else { "  <-- non-ASCII characters" };
+            let invalid = if line.chars().all(|c| priority(c).is_some()) {
+                ""
+            } else {
+                "  <-- invalid items"
+            };
+            println!(
+                "Rucksack {:>4}: [{listing}]{flag}{odd}{non_ascii}{invalid}",
+                index + 1
+            );
+            if !(flag.is_empty() && odd.is_empty() && invalid.is_empty()) {
+                flagged += 1;
+            }
+            // Sum up the priorities of all shared items so the total is still meaningful.
+            total += items.iter().filter_map(|&i| priority(i)).sum::<u32>();
+            continue;
+        }
+
+        // The puzzle guarantees exactly one shared item per rucksack.
+        if items.len() != 1 {
+            panic!(
+                "Rucksack {} has {} shared items instead of one. Run with --report for details.",
+                index + 1,
+                items.len()
+            );
+        }
         // Accumulate the priorities.
-        total += priority;
+        let item = *items.first().unwrap();
+        total += priority(item).unwrap_or_else(|| {
+            panic!(
+                "Rucksack {} shares the invalid item {item:?}. Run with --report for details.",
+                index + 1
+            )
+        });
+    }
+
+    if report {
+        println!("Flagged rucksacks: {flagged}");
     }
     println!("Total priority: {total}");
 }
-
@@ -1,35 +1,100 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
+
+// Calculate the priority of an item. Only the letters a-z and A-Z are valid items.
+fn priority(item: char) -> Option<u32> {
+    match item {
+        item if item.is_ascii_lowercase() => Some((item as u32) - ('a' as u32) + 1),
+        item if item.is_ascii_uppercase() => Some((item as u32) - ('A' as u32) + 27),
+        _ => None,
+    }
+}
+
+// Describe an item and its priority for the report.
+fn describe(item: char) -> String {
+    match priority(item) {
+        Some(p) => format!("{item} ({p})"),
+        None => format!("{item:?} (invalid)"),
+    }
+}
+
+// Determine all items that are common to every rucksack of a group.
+// A BTreeSet keeps the result sorted, which makes the report deterministic.
+fn badge_candidates(group: &[&str]) -> BTreeSet<char> {
+    let mut sets = group.iter().map(|l| l.chars().collect::<BTreeSet<char>>());
+    let first = sets.next().unwrap_or_default();
+    // Use set intersection to narrow it down to the items common to all sets.
+    sets.fold(first, |acc, set| acc.intersection(&set).copied().collect())
+}
 
 fn main() {
     // Use command line arguments to specify the input filename.
     let args: Vec<String> = std::env::args().collect();
     if args.len() < 2 {
-        panic!("Usage: ./main <input-file>\nNo input file provided. Exiting.");
+        panic!("Usage: ./main <input-file> [--report]\nNo input file provided. Exiting.");
     }
+    // With --report, list the badge candidates of every group instead of only summing them up.
+    let report = args.iter().skip(2).any(|a| a == "--report");
 
     // Next, read the contents of the input file into a string for easier processing.
     let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
     // Line-by-line processing is easiest.
-    let mut input = input.lines();
+    let input = input.lines().collect::<Vec<_>>();
 
     // --- TASK BEGIN ---
     let mut total = 0;
-    while let Some(line) = input.next() {
-        // Read the next three lines and turn them into HashSets.
-        let first: HashSet<char> = line.chars().collect();
-        let second: HashSet<char> = input.next().unwrap().chars().collect();
-        let third: HashSet<char> = input.next().unwrap().chars().collect();
-        // Use set intersection to determine the item that's common to all three sets.
-        let item = *first.intersection(&second).copied().collect::<HashSet<char>>().intersection(&third).next().unwrap();
-        // Calculate the priority.
-        let priority = match item {
-            item if item.is_ascii_lowercase() => (item as u32) - ('a' as u32) + 1,
-            item if item.is_ascii_uppercase() => (item as u32) - ('A' as u32) + 27,
-            _ => {panic!("Character out of range")}
-        };
+    let mut flagged = 0;
+    // Every group consists of three consecutive lines.
+    for (index, group) in input.chunks(3).enumerate() {
+        let items = badge_candidates(group);
+
+        if report {
+            // Describe the group and flag everything that isn't exactly one badge candidate.
+            let listing = items
+                .iter()
+                .map(|&i| describe(i))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let flag = match items.len() {
+                1 => "",
+                0 => "  <-- no badge candidate",
+                _ => "  <-- several badge candidates",
+            };
+            let short = if group.len() < 3 { "  <-- incomplete group" } else { "" };
+            let invalid = if group.iter().flat_map(|l| l.chars()).all(|c| priority(c).is_some()) {
+                ""
+            } else {
+                "  <-- invalid items"
+            };
+            println!("Group {:>4}: [{listing}]{flag}{short}{invalid}", index + 1);
+            if !(flag.is_empty() && short.is_empty() && invalid.is_empty()) {
+                flagged += 1;
+            }
+            // Sum up the priorities of all candidates so the total is still meaningful.
+            total += items.iter().filter_map(|&i| priority(i)).sum::<u32>();
+            continue;
+        }
+
+        // The puzzle guarantees complete groups with exactly one badge each.
+        if group.len() != 3 || items.len() != 1 {
+            panic!(
+                "Group {} has {} rucksacks and {} badge candidates. Run with --report for details.",
+                index + 1,
+                group.len(),
+                items.len()
+            );
+        }
         // Accumulate the priorities.
-        total += priority;
+        let item = *items.first().unwrap();
+        total += priority(item).unwrap_or_else(|| {
+            panic!(
+                "Group {} shares the invalid item {item:?}. Run with --report for details.",
+                index + 1
+            )
+        });
+    }
+
+    if report {
+        println!("Flagged groups: {flagged}");
     }
     println!("Total priority: {total}");
 }
-
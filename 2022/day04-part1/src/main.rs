@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use aoc_intervals::Interval;
 
 fn main() {
     // Use command line arguments to specify the input filename.
@@ -23,21 +23,16 @@ fn main() {
             .iter()
             .map(|x| x.split('-').collect::<Vec<_>>())
             .collect::<Vec<_>>();
-        // Turn [["1", "2"], ["3", "6"]] into [1..=2, 3..=6]
+        // Turn [["1", "2"], ["3", "6"]] into the intervals [1-2, 3-6].
+        // Only the endpoints are stored, so this is O(1) no matter how wide the ranges are.
         let ranges = ranges
             .iter()
-            .map(|x| (x[0].parse::<i32>().unwrap())..=(x[1].parse::<i32>().unwrap()))
-            .collect::<Vec<_>>();
-        // Now turn the ranges into HashSets containing the respective integers. Essentially:
-        // Turn [1..=2, 3..=6] into [{1,2}, {3,4,5,6}]
-        let sets = ranges
-            .into_iter()
-            .map(|x| x.collect::<HashSet<_>>())
+            .map(|x| Interval::new(x[0].parse::<i64>().unwrap(), x[1].parse::<i64>().unwrap()))
             .collect::<Vec<_>>();
 
-        // Check if either of the sets is a subset of the other, i.e. is fully contained in the other.
+        // Check if either of the ranges is fully contained in the other.
         // If so, add it to the count.
-        if sets[0].is_subset(&sets[1]) || sets[1].is_subset(&sets[0]) {
+        if ranges[0].contains_interval(&ranges[1]) || ranges[1].contains_interval(&ranges[0]) {
             count += 1;
         }
     }
@@ -1,11 +1,60 @@
-use std::collections::HashSet;
+use aoc_intervals::{coverage, Interval, IntervalSet};
+use std::collections::BTreeMap;
+
+// Print an extended analysis of how the section assignments cover the sections.
+fn print_coverage(pairs: &[(Interval, Interval)]) {
+    let all = pairs.iter().flat_map(|p| [p.0, p.1]).collect::<Vec<_>>();
+    if all.is_empty() {
+        println!("No section assignments found.");
+        return;
+    }
+
+    // Sweep over all assignments at once to get the coverage per segment,
+    // then tally up how many sections are covered by how many elves.
+    let mut histogram: BTreeMap<usize, u64> = BTreeMap::new();
+    for (segment, elves) in coverage(&all) {
+        *histogram.entry(elves).or_default() += segment.len();
+    }
+
+    // Sections nobody cleans are the gaps between the lowest and the highest assigned section.
+    let cleaned: IntervalSet = all.iter().copied().collect();
+    let bounds = Interval::new(
+        all.iter().map(|i| i.start()).min().unwrap(),
+        all.iter().map(|i| i.end()).max().unwrap(),
+    );
+    let uncleaned = IntervalSet::from_iter(cleaned.gaps(&bounds));
+    if !uncleaned.is_empty() {
+        histogram.insert(0, uncleaned.len());
+    }
+
+    println!("Sections {bounds}:");
+    for (elves, sections) in &histogram {
+        println!("  covered by {elves:>3} elves: {sections} sections");
+    }
+    println!("Sections nobody cleans: {} in {}", uncleaned.len(), uncleaned);
+
+    // Finally, find the pairs with the biggest overlap.
+    let overlaps = pairs
+        .iter()
+        .map(|p| p.0.intersection(&p.1).map_or(0, |i| i.len()))
+        .collect::<Vec<_>>();
+    let biggest = overlaps.iter().copied().max().unwrap_or(0);
+    println!("Biggest overlap within a pair: {biggest} sections");
+    for (idx, (a, b)) in pairs.iter().enumerate() {
+        if biggest > 0 && overlaps[idx] == biggest {
+            println!("  pair {:>4}: {a},{b}", idx + 1);
+        }
+    }
+}
 
 fn main() {
     // Use command line arguments to specify the input filename.
     let args: Vec<String> = std::env::args().collect();
     if args.len() < 2 {
-        panic!("Usage: ./main <input-file>\nNo input file provided. Exiting.");
+        panic!("Usage: ./main <input-file> [--coverage]\nNo input file provided. Exiting.");
     }
+    // With --coverage, additionally analyze the coverage across all pairs.
+    let analyze = args.iter().skip(2).any(|a| a == "--coverage");
 
     // Next, read the contents of the input file into a string for easier processing.
     let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
@@ -14,6 +63,7 @@ fn main() {
 
     // --- TASK BEGIN ---
     let mut count = 0;
+    let mut pairs: Vec<(Interval, Interval)> = Vec::new();
 
     for line in input {
         // Turn "1-2,3-6" into ["1-2", "3-6"].
@@ -23,24 +73,24 @@ fn main() {
             .iter()
             .map(|x| x.split('-').collect::<Vec<_>>())
             .collect::<Vec<_>>();
-        // Turn [["1", "2"], ["3", "6"]] into [1..=2, 3..=6]
+        // Turn [["1", "2"], ["3", "6"]] into the intervals [1-2, 3-6].
+        // Only the endpoints are stored, so this is O(1) no matter how wide the ranges are.
         let ranges = ranges
             .iter()
-            .map(|x| (x[0].parse::<i32>().unwrap())..=(x[1].parse::<i32>().unwrap()))
-            .collect::<Vec<_>>();
-        // Now turn the ranges into HashSets containing the respective integers. Essentially:
-        // Turn [1..=2, 3..=6] into [{1,2}, {3,4,5,6}]
-        let sets = ranges
-            .into_iter()
-            .map(|x| x.collect::<HashSet<_>>())
+            .map(|x| Interval::new(x[0].parse::<i64>().unwrap(), x[1].parse::<i64>().unwrap()))
             .collect::<Vec<_>>();
 
-        // Check if the two sets are disjoint.
-        // If there's at least one overlapping element, add it to the count.
-        if !sets[0].is_disjoint(&sets[1]) {
+        // Check if the two ranges overlap.
+        // If there's at least one overlapping section, add it to the count.
+        if ranges[0].overlaps(&ranges[1]) {
             count += 1;
         }
+        pairs.push((ranges[0], ranges[1]));
     }
 
     println!("Count: {}", count);
+
+    if analyze {
+        print_coverage(&pairs);
+    }
 }
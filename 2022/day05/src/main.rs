@@ -0,0 +1,289 @@
+#[derive(Copy, Clone, Debug)]
+struct MoveOperation {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl MoveOperation {
+    // The move operation that puts the same crates back where they came from.
+    fn inverse(&self) -> MoveOperation {
+        MoveOperation {
+            amount: self.amount,
+            from: self.to,
+            to: self.from,
+        }
+    }
+}
+
+// The cargo hold consists of one stack of crate labels per lane, bottom to top.
+type CargoHold = Vec<Vec<String>>;
+
+// A crane model, which determines how a MoveOperation rearranges the cargo hold.
+trait Crane {
+    // The name of the model, for printing purposes.
+    fn name(&self) -> &'static str;
+    // Execute a single move operation on the cargo hold.
+    fn apply(&self, cargo_hold: &mut CargoHold, mop: &MoveOperation);
+    // Undo a single move operation that was previously executed with `apply`.
+    // Both models are symmetric: moving the crates back in the opposite direction
+    // restores their original order, whether they're moved one-by-one or all at once.
+    fn unapply(&self, cargo_hold: &mut CargoHold, mop: &MoveOperation) {
+        self.apply(cargo_hold, &mop.inverse());
+    }
+}
+
+// The CrateMover 9000 moves crates one-by-one.
+struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> &'static str {
+        "CrateMover 9000"
+    }
+
+    fn apply(&self, cargo_hold: &mut CargoHold, mop: &MoveOperation) {
+        // We move boxes one-by-one, from the top of a stack to the top of another stack.
+        for _ in 0..mop.amount {
+            // Grab the topmost box from the "from" stack.
+            let cargo = cargo_hold[mop.from].pop().unwrap();
+            // Put said box onto the "to" stack.
+            cargo_hold[mop.to].push(cargo);
+        }
+    }
+}
+
+// The CrateMover 9001 moves multiple crates at once, retaining their order.
+struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> &'static str {
+        "CrateMover 9001"
+    }
+
+    fn apply(&self, cargo_hold: &mut CargoHold, mop: &MoveOperation) {
+        // We move boxes as a whole stack.
+        // Determine the size of the "from"-stack.
+        let stack_size = cargo_hold[mop.from].len();
+        // Cut off the amount required from the "from"-stack and move it to "cargo".
+        let mut cargo = cargo_hold[mop.from].split_off(stack_size - mop.amount);
+        // And then add those boxes to the destination stack.
+        cargo_hold[mop.to].append(&mut cargo);
+    }
+}
+
+// Execute all move operations with the given crane and return the final cargo hold.
+// `on_step` gets called after every move, which allows printing or recording each step.
+fn run(
+    crane: &dyn Crane,
+    cargo_hold: &CargoHold,
+    move_operations: &[MoveOperation],
+    mut on_step: impl FnMut(usize, &MoveOperation, &CargoHold),
+) -> CargoHold {
+    let mut cargo_hold = cargo_hold.clone();
+    for (step, mop) in move_operations.iter().enumerate() {
+        // Make sure the move is actually possible before touching the cargo hold.
+        check_move(&cargo_hold, step, mop);
+        crane.apply(&mut cargo_hold, mop);
+        on_step(step, mop, &cargo_hold);
+    }
+    cargo_hold
+}
+
+// Undo all move operations, starting with the last one, and return the initial cargo hold.
+fn run_backwards(
+    crane: &dyn Crane,
+    cargo_hold: &CargoHold,
+    move_operations: &[MoveOperation],
+) -> CargoHold {
+    let mut cargo_hold = cargo_hold.clone();
+    for (step, mop) in move_operations.iter().enumerate().rev() {
+        // Undoing a move takes the crates off of its destination stack.
+        check_move(&cargo_hold, step, &mop.inverse());
+        crane.unapply(&mut cargo_hold, mop);
+    }
+    cargo_hold
+}
+
+// Panic with a helpful message if the move operation can't be executed on the cargo hold.
+fn check_move(cargo_hold: &CargoHold, step: usize, mop: &MoveOperation) {
+    if mop.from >= cargo_hold.len() || mop.to >= cargo_hold.len() {
+        panic!("Move {} refers to a lane that doesn't exist", step + 1);
+    }
+    if cargo_hold[mop.from].len() < mop.amount {
+        panic!(
+            "Move {} takes {} crates from lane {}, which only holds {}",
+            step + 1,
+            mop.amount,
+            mop.from + 1,
+            cargo_hold[mop.from].len()
+        );
+    }
+}
+
+// Concatenate each stack's topmost crate.
+fn tops(cargo_hold: &CargoHold) -> String {
+    cargo_hold
+        .iter()
+        .map(|stack| stack.last().map_or("", |c| c.as_str()))
+        .collect()
+}
+
+// Parse the drawing of the cargo hold into one stack of crate labels per lane.
+// The number of lanes is taken from the numbered footer line, and every crate is assigned
+// to the lane whose number sits closest to the crate's center. This way, rows that are
+// short or had their trailing spaces trimmed work just as well as labels longer than one char.
+fn parse_drawing(drawing: &[&str]) -> CargoHold {
+    // The last line of the drawing is the footer, e.g. " 1   2   3 ".
+    let (footer, rows) = drawing
+        .split_last()
+        .expect("The drawing of the cargo hold is missing");
+    // Remember the column at which each lane's number is centered.
+    let lane_columns = tokens(footer, |c| c.is_whitespace())
+        .map(|(start, token)| start + (token.len() - 1) / 2)
+        .collect::<Vec<_>>();
+    if lane_columns.is_empty() {
+        panic!("The footer of the drawing doesn't number any lanes");
+    }
+
+    let mut cargo_hold: CargoHold = vec![Vec::new(); lane_columns.len()];
+    // Go through the rows bottom-up, so crates can simply be pushed onto their stacks.
+    for row in rows.iter().rev() {
+        // Every crate looks like "[X]", with an arbitrary label in between the brackets.
+        for (start, token) in tokens(row, |c| c.is_whitespace() || c == ']') {
+            let label = token.trim_start_matches('[');
+            // The crate spans from its opening '[' up to and including its closing ']'.
+            let center = start + token.len() / 2;
+            let lane = (0..lane_columns.len())
+                .min_by_key(|&l| lane_columns[l].abs_diff(center))
+                .unwrap();
+            cargo_hold[lane].push(label.to_string());
+        }
+    }
+    cargo_hold
+}
+
+// Split a line into its non-empty tokens, together with the column each token starts at.
+fn tokens(line: &str, is_separator: impl Fn(char) -> bool) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = 0;
+    line.split(is_separator).filter_map(move |token| {
+        let column = start;
+        start += token.len() + 1;
+        (!token.is_empty()).then_some((column, token))
+    })
+}
+
+// Render the cargo hold in the same format as the puzzle input, including the numbered footer.
+fn format_drawing(cargo_hold: &CargoHold) -> String {
+    // Every lane is as wide as its widest crate, so longer labels stay aligned.
+    let width = cargo_hold
+        .iter()
+        .flatten()
+        .map(|label| label.len())
+        .max()
+        .unwrap_or(1)
+        + 2;
+    let height = cargo_hold.iter().map(|s| s.len()).max().unwrap_or(0);
+
+    let mut lines = Vec::new();
+    // Draw the rows top-down, leaving a blank space wherever a stack is too low.
+    for row in (0..height).rev() {
+        let line = cargo_hold
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(label) => format!("{:^width$}", format!("[{label}]")),
+                None => " ".repeat(width),
+            })
+            .collect::<Vec<_>>();
+        lines.push(line.join(" "));
+    }
+    // And finally the footer with the lane numbers, centered below each lane.
+    let footer = (1..=cargo_hold.len())
+        .map(|n| format!("{n:^width$}"))
+        .collect::<Vec<_>>();
+    lines.push(footer.join(" "));
+    lines.join("\n")
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [--replay] [--reverse]\nNo input file provided. Exiting."
+        );
+    }
+    // With --replay, print the cargo hold after every single move.
+    let replay = args.iter().skip(2).any(|a| a == "--replay");
+    // With --reverse, the drawing is the final layout and the initial one is reconstructed.
+    let reverse = args.iter().skip(2).any(|a| a == "--reverse");
+
+    // Next, read the contents of the input file into a string for easier processing.
+    let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
+    // Line-by-line processing is easiest.
+    let mut input = input.lines();
+
+    // --- TASK BEGIN ---
+
+    // First of all, parse the text input into our own data structures for easier solving.
+
+    // The drawing of the cargo hold ends with the first empty line.
+    let drawing = input
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+    // Create the data structure representing the cargo hold.
+    let cargo_hold = parse_drawing(&drawing);
+
+    // Next, parse all of the move oprations.
+
+    // Create the data structure holding all of the move operations.
+    let mut move_operations: Vec<MoveOperation> = Vec::new();
+
+    for line in input {
+        // Turn "move x from y to z" into ["move", "x", "from", "y", "to", "z"]
+        let words = line.split(' ').collect::<Vec<_>>();
+        // Parse x, y and z and create a new MoveOperation with it.
+        move_operations.push(MoveOperation {
+            amount: words[1].parse::<usize>().unwrap(),
+            from: words[3].parse::<usize>().unwrap() - 1,
+            to: words[5].parse::<usize>().unwrap() - 1,
+        });
+    }
+
+    // Now that we have all of the data, start executing.
+    // Run the same move operations with both crane models.
+    let cranes: [&dyn Crane; 2] = [&CrateMover9000, &CrateMover9001];
+
+    if reverse {
+        for crane in cranes {
+            // Run all moves backwards to find the drawing we must have started with.
+            let initial_hold = run_backwards(crane, &cargo_hold, &move_operations);
+            println!("Initial drawing for the {}:", crane.name());
+            println!("{}\n", format_drawing(&initial_hold));
+            // Double-check by running the moves forward again.
+            let final_hold = run(crane, &initial_hold, &move_operations, |_, _, _| {});
+            if final_hold != cargo_hold {
+                panic!("Replaying the moves doesn't lead back to the given drawing");
+            }
+        }
+        return;
+    }
+
+    for crane in cranes {
+        let final_hold = run(crane, &cargo_hold, &move_operations, |step, mop, hold| {
+            if replay {
+                println!(
+                    "{} - move {}: {} from {} to {}",
+                    crane.name(),
+                    step + 1,
+                    mop.amount,
+                    mop.from + 1,
+                    mop.to + 1
+                );
+                println!("{}\n", format_drawing(hold));
+            }
+        });
+        // Print the string with each stack's topmost cargo.
+        println!("Solution for the {}: {}", crane.name(), tops(&final_hold));
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+
+// Keeps track of how many distinct bytes the last `length` bytes of a stream contain.
+// Instead of rebuilding a set for every byte, a count per byte value is updated
+// as bytes enter and leave the window, so every step is O(1).
+struct Window {
+    length: usize,
+    counts: [u32; 256],
+    distinct: usize,
+}
+
+impl Window {
+    fn new(length: usize) -> Window {
+        Window {
+            length,
+            counts: [0; 256],
+            distinct: 0,
+        }
+    }
+
+    // Add a byte to the window.
+    fn add(&mut self, byte: u8) {
+        if self.counts[byte as usize] == 0 {
+            self.distinct += 1;
+        }
+        self.counts[byte as usize] += 1;
+    }
+
+    // Remove a byte that was previously added to the window.
+    fn remove(&mut self, byte: u8) {
+        self.counts[byte as usize] -= 1;
+        if self.counts[byte as usize] == 0 {
+            self.distinct -= 1;
+        }
+    }
+
+    // Check whether all bytes in the window are distinct.
+    // Only meaningful once the window has been filled completely.
+    fn is_marker(&self) -> bool {
+        self.distinct == self.length
+    }
+}
+
+// Detects markers of several lengths at once, consuming the stream byte by byte.
+struct MarkerDetector {
+    windows: Vec<Window>,
+    // Ringbuffer holding the last bytes, long enough for the longest window.
+    history: Vec<u8>,
+    // The number of bytes consumed so far.
+    position: u64,
+}
+
+impl MarkerDetector {
+    fn new(lengths: &[usize]) -> MarkerDetector {
+        let longest = lengths.iter().copied().max().unwrap_or(0);
+        MarkerDetector {
+            windows: lengths.iter().map(|&l| Window::new(l)).collect(),
+            history: vec![0; longest.max(1)],
+            position: 0,
+        }
+    }
+
+    // Consume the next byte of the stream.
+    // `on_marker` gets called for every window whose last `length` bytes are now all distinct.
+    fn push(&mut self, byte: u8, mut on_marker: impl FnMut(Marker)) {
+        let capacity = self.history.len() as u64;
+        for window in self.windows.iter_mut() {
+            window.add(byte);
+            // Once the window is full, the byte that just fell out of it has to go.
+            let length = window.length as u64;
+            if self.position >= length {
+                let outgoing = self.history[((self.position - length) % capacity) as usize];
+                window.remove(outgoing);
+            }
+            if self.position + 1 >= length && window.is_marker() {
+                on_marker(Marker {
+                    length: window.length,
+                    position: self.position + 1,
+                });
+            }
+        }
+        self.history[(self.position % capacity) as usize] = byte;
+        self.position += 1;
+    }
+
+    // Consume the given bytes lazily, yielding every marker found along the way.
+    fn markers<I: Iterator<Item = u8>>(&mut self, bytes: I) -> Markers<'_, I> {
+        Markers {
+            detector: self,
+            bytes,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+// A position right after the last `length` bytes of the stream were all distinct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Marker {
+    length: usize,
+    position: u64,
+}
+
+// Iterator over all markers in a stream of bytes, see `MarkerDetector::markers`.
+struct Markers<'a, I: Iterator<Item = u8>> {
+    detector: &'a mut MarkerDetector,
+    bytes: I,
+    // A single byte can complete markers of several lengths at once.
+    pending: VecDeque<Marker>,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Markers<'_, I> {
+    type Item = Marker;
+
+    fn next(&mut self) -> Option<Marker> {
+        while self.pending.is_empty() {
+            let byte = self.bytes.next()?;
+            let pending = &mut self.pending;
+            self.detector.push(byte, |marker| pending.push_back(marker));
+        }
+        self.pending.pop_front()
+    }
+}
+
+// The reason why a stream doesn't contain a marker of a given length.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum NoMarker {
+    // The stream is shorter than the marker itself.
+    StreamTooShort { stream_length: u64 },
+    // Every window of the stream contains at least one repeated character.
+    NoDistinctWindow,
+}
+
+impl fmt::Display for NoMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoMarker::StreamTooShort { stream_length } => {
+                write!(
+                    f,
+                    "no marker found, the stream is only {stream_length} characters long"
+                )
+            }
+            NoMarker::NoDistinctWindow => write!(f, "no marker found"),
+        }
+    }
+}
+
+// Summary of all markers of one particular length.
+struct MarkerReport {
+    length: usize,
+    first: Result<u64, NoMarker>,
+    count: u64,
+}
+
+// Scan a whole stream for markers of all the given lengths, in a single pass.
+// The stream is read in chunks, so arbitrarily large inputs work in constant memory.
+// `on_marker` gets called for every single marker found.
+fn scan(
+    mut reader: impl Read,
+    lengths: &[usize],
+    mut on_marker: impl FnMut(Marker),
+) -> std::io::Result<Vec<MarkerReport>> {
+    let mut detector = MarkerDetector::new(lengths);
+    let mut reports = lengths
+        .iter()
+        .map(|&length| MarkerReport {
+            length,
+            first: Err(NoMarker::NoDistinctWindow),
+            count: 0,
+        })
+        .collect::<Vec<_>>();
+
+    let mut buffer = vec![0u8; 1 << 16];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        // The datastream is a single line of text, so line breaks aren't part of it.
+        let bytes = buffer[..read]
+            .iter()
+            .copied()
+            .filter(|&b| b != b'\n' && b != b'\r');
+        for marker in detector.markers(bytes) {
+            let report = reports
+                .iter_mut()
+                .find(|r| r.length == marker.length)
+                .unwrap();
+            if report.first.is_err() {
+                report.first = Ok(marker.position);
+            }
+            report.count += 1;
+            on_marker(marker);
+        }
+    }
+
+    // Tell streams that are too short apart from those that simply contain no marker.
+    for report in &mut reports {
+        if report.first.is_err() && detector.position < report.length as u64 {
+            report.first = Err(NoMarker::StreamTooShort {
+                stream_length: detector.position,
+            });
+        }
+    }
+    Ok(reports)
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [--all] [marker-lengths ...]\nNo input file provided. Exiting."
+        );
+    }
+    // With --all, print the position of every marker, not only the first one.
+    let all = args.iter().skip(2).any(|a| a == "--all");
+
+    // Define the lengths of characters that need to be unique.
+    // By default, look for start-of-packet (part one) and start-of-message (part two) markers.
+    let mut lengths = args[2..]
+        .iter()
+        .filter(|a| *a != "--all")
+        .map(|a| {
+            a.parse::<usize>()
+                .expect("Marker lengths must be positive integers")
+        })
+        .collect::<Vec<_>>();
+    if lengths.is_empty() {
+        lengths = vec![4, 14];
+    }
+    // Every length only needs to be reported once.
+    let mut seen = Vec::new();
+    lengths.retain(|&l| {
+        let new = !seen.contains(&l);
+        seen.push(l);
+        new
+    });
+    if lengths.contains(&0) || lengths.iter().any(|&l| l > 256) {
+        panic!("Marker lengths must lie between 1 and 256, as there are only 256 distinct bytes");
+    }
+
+    // Stream the input file instead of reading it into memory as a whole.
+    let input = File::open(&args[1]).expect("Error opening file");
+    let reports = scan(input, &lengths, |marker| {
+        if all {
+            println!("Marker of length {} at {}", marker.length, marker.position);
+        }
+    })
+    .expect("Error reading file");
+
+    for report in reports {
+        match report.first {
+            Ok(position) => println!(
+                "Marker of length {}: first at {}, {} in total",
+                report.length, position, report.count
+            ),
+            Err(reason) => println!("Marker of length {}: {}", report.length, reason),
+        }
+    }
+}
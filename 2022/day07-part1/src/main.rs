@@ -1,5 +1,3 @@
-use std::{cell::RefCell, fmt, rc::Rc};
-
 // Custom data structure
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 enum NodeType {
@@ -9,38 +7,108 @@ enum NodeType {
 
 use NodeType::*;
 
-// #[derive(Debug)]
+// Index of a node within the FileSystem's arena.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+struct NodeId(usize);
+
+// Nodes refer to their parent and children by NodeId instead of pointers,
+// so there are no reference cycles and the derived Debug output stays finite.
+#[derive(Debug)]
 struct Node {
     node_type: NodeType,
     name: String,
     size: usize,
-    children: Vec<Rc<RefCell<Node>>>,
-    parent: Option<Rc<RefCell<Node>>>,
+    children: Vec<NodeId>,
+    parent: Option<NodeId>,
 }
 
-// Manual implementation of Debug for Node.
-// Necessary in order to remove the "parent" field from the output.
-// Otherwise the printer will print indefinitely.
-impl fmt::Debug for Node {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Node")
-            .field("node_type", &self.node_type)
-            .field("name", &self.name)
-            .field("size", &self.size)
-            .field("children", &self.children)
-            .finish()
-    }
+// Arena owning all nodes of the filesystem. The root directory always has NodeId(0).
+#[derive(Debug)]
+struct FileSystem {
+    nodes: Vec<Node>,
 }
 
-impl Node {
-    fn new(node_type: NodeType, name: &str, size: usize) -> Rc<RefCell<Node>> {
-        Rc::new(RefCell::new(Node {
+impl FileSystem {
+    // Create a filesystem containing nothing but the root directory.
+    fn new() -> FileSystem {
+        FileSystem {
+            nodes: vec![Node {
+                node_type: Directory,
+                name: String::from("/"),
+                size: 0,
+                children: Vec::new(),
+                parent: None,
+            }],
+        }
+    }
+
+    fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    // Create a new node and attach it to the given parent directory.
+    fn add_child(
+        &mut self,
+        parent: NodeId,
+        node_type: NodeType,
+        name: &str,
+        size: usize,
+    ) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
             node_type,
             name: String::from(name),
             size,
             children: Vec::new(),
-            parent: None,
-        }))
+            parent: Some(parent),
+        });
+        self.nodes[parent.0].children.push(id);
+        id
+    }
+
+    // Find the child of a directory with the given name.
+    fn child_by_name(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.node(dir)
+            .children
+            .iter()
+            .copied()
+            .find(|&c| self.node(c).name == name)
+    }
+
+    // Iterate over all directories in the filesystem, including the root.
+    fn directories(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.iter().filter(|n| n.node_type == Directory)
+    }
+
+    // Perform a depth-first-search on the tree in order to annotate the directory sizes.
+    // The search uses an explicit stack instead of recursion, so arbitrarily deep trees
+    // can't overflow the call stack.
+    fn calc_node_size(&mut self) {
+        // Every directory is visited twice: Once on the way down, pushing its children,
+        // and once more on the way up, after all of its children have been sized.
+        let mut stack = vec![(self.root(), false)];
+        while let Some((id, children_done)) = stack.pop() {
+            if self.node(id).node_type == File {
+                continue;
+            }
+            if children_done {
+                // One more thing: Accumulate the size of all children into this directory.
+                let size = self
+                    .node(id)
+                    .children
+                    .iter()
+                    .map(|&c| self.node(c).size)
+                    .sum();
+                self.nodes[id.0].size = size;
+            } else {
+                stack.push((id, true));
+                stack.extend(self.node(id).children.iter().map(|&c| (c, false)));
+            }
+        }
     }
 }
 
@@ -59,15 +127,10 @@ fn main() {
     // --- TASK BEGIN ---
 
     // Set up our filesystem, starting with the root directory as the root node.
-    let root = Node::new(Directory, "/", 0);
-
-    // Also keep track of all directories in the graph separately.
-    // This will help us actually determine the sum we're supposed to look for.
-    let mut dir_list: Vec<Rc<RefCell<Node>>> = Vec::new();
-    dir_list.push(root.clone());
+    let mut fs = FileSystem::new();
 
     // Also set up a pointer for the current directory.
-    let mut cd = root.clone();
+    let mut cd = fs.root();
 
     // Keep track whether we're currently reading directories.
     let mut ls_mode = false;
@@ -86,33 +149,20 @@ fn main() {
                     match line[2] {
                         "/" => {
                             // If the target is "/" switch back to the root directory.
-                            cd = root.clone();
+                            cd = fs.root();
                         }
                         ".." => {
                             // Switch to parent directory.
-                            // First, we need to borrow the actual Node of the current directory.
-                            let cd_node = cd.borrow();
-                            // Grab an Rc to the parent...
-                            let pd = cd_node.parent.clone().unwrap();
-                            drop(cd_node);
-                            // ...and assign it to cd.
-                            cd = pd;
+                            cd = fs
+                                .node(cd)
+                                .parent
+                                .expect("Transcript tries to leave the root directory");
                         }
                         dir => {
                             // In the standard case, look for the child-node with the correct name.
-                            // Get from the Rc<RefCell<Node>> to the actual Node.
-                            let cd_node = cd.borrow();
-                            // From there, find the child directory with the matching name.
-                            let target_dir = cd_node
-                                .children
-                                .iter()
-                                .find(|x| x.borrow().name == dir)
-                                .unwrap()
-                                .clone();
-                            // Drop the Ref<_, Node> to ensure we drop the borrow on cd.
-                            drop(cd_node);
-                            // Now assign the new "current directory".
-                            cd = target_dir;
+                            cd = fs.child_by_name(cd, dir).unwrap_or_else(|| {
+                                panic!("No directory named {dir} to change into")
+                            });
                         }
                     }
                 }
@@ -136,29 +186,20 @@ fn main() {
                 size = line[0].parse::<usize>().unwrap();
             }
 
-            // Now create the node for this file / directory.
-            let node = Node::new(node_type, name, size);
-            // Update the children of the current directory.
-            cd.borrow_mut().children.push(node.clone());
-            // And ensure the new node points back to the current directory.
-            node.borrow_mut().parent = Some(cd.clone());
-
-            // Also add any new directories to the directory list.
-            if node_type == Directory {
-                dir_list.push(node.clone());
-            }
+            // Now create the node for this file / directory within the current directory.
+            fs.add_child(cd, node_type, name, size);
         }
     }
 
     // All files and directories have been parsed into the data structure.
     // However, the size on all directories is currently 0.
-    // Determine the size of all directories recursively using DFS.
-    calc_node_size(root.clone());
+    // Determine the size of all directories using DFS.
+    fs.calc_node_size();
 
     // Finally, actually perform what the task requested.
     let mut total: usize = 0;
-    for dir in &dir_list {
-        let size = dir.borrow().size;
+    for dir in fs.directories() {
+        let size = dir.size;
         if size <= 100000 {
             total += size;
         }
@@ -166,24 +207,3 @@ fn main() {
 
     println!("Result: {}", total);
 }
-
-// Perform a depth-first-search on the tree in order to annotate the directory sizes.
-// This method uses recursion to visit the entire tree.
-fn calc_node_size(node: Rc<RefCell<Node>>) -> usize {
-    let mut inner_node = node.borrow_mut();
-    // If this node is a file, there's no recursion to be done.
-    // Simply return the size of the file.
-    if inner_node.node_type == File {
-        inner_node.size
-    } else {
-        let mut size: usize = 0;
-        // Iterate through all children and ...
-        for child in &inner_node.children {
-            // ... accumulate the size of all nodes recursively.
-            size += calc_node_size(child.clone());
-        }
-        // One more thing: Update the size of this directory before returning.
-        inner_node.size = size;
-        size
-    }
-}
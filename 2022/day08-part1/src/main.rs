@@ -30,16 +30,55 @@ impl Tree {
 #[derive(Debug)]
 struct Forest {
     field: Vec<Tree>,
-    dim: usize,
+    width: usize,
+    height: usize,
 }
 
 impl Forest {
+    // Parse the forest from the puzzle input, one row of digits per line.
+    // The dimensions are taken from the input itself, which has to be rectangular.
+    fn parse(input: &str) -> Result<Forest, String> {
+        let mut forest = Forest {
+            field: Vec::new(),
+            width: 0,
+            height: 0,
+        };
+
+        // Simply iterate through all lines and characters.
+        for (y, line) in input.lines().enumerate() {
+            // The first line determines the width, all others have to match it.
+            if y == 0 {
+                forest.width = line.len();
+            } else if line.len() != forest.width {
+                return Err(format!(
+                    "Line {} has {} trees, expected {} like the first line",
+                    y + 1,
+                    line.len(),
+                    forest.width
+                ));
+            }
+            for (x, char) in line.chars().enumerate() {
+                // Convert the character value into the respective number.
+                let height = char
+                    .to_digit(10)
+                    .ok_or_else(|| format!("Invalid tree height {char:?} at ({x},{y})"))?;
+                forest.field.push(Tree::new(height as i8));
+            }
+            forest.height += 1;
+        }
+
+        if forest.field.is_empty() {
+            return Err(String::from("The forest is empty"));
+        }
+        Ok(forest)
+    }
+
     // Pretty printer for the forest, using terminal escape codes to color
     // the hidden trees bold and red.
     fn print(&self) {
-        for y in 0..self.dim {
-            for x in 0..self.dim {
-                let tree = self.field[y * self.dim + x];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let tree = self.field[y * self.width + x];
                 if !tree.visible() {
                     print!("\x1b[1;31m");
                 }
@@ -54,55 +93,39 @@ impl Forest {
 
     // Easy accessor for a tree using x and y coordintes.
     fn at(&mut self, x: usize, y: usize) -> &mut Tree {
-        &mut self.field[y * self.dim + x]
+        &mut self.field[y * self.width + x]
     }
 }
 
 fn main() {
     // Use command line arguments to specify the input filename.
     let args: Vec<String> = std::env::args().collect();
-    if args.len() < 3 {
-        panic!("Usage: ./main <input-file> <map-dimensions>\nNot enough arguments. Exiting.");
+    if args.len() < 2 {
+        panic!("Usage: ./main <input-file>\nNo input file provided. Exiting.");
     }
 
     // Next, read the contents of the input file into a string for easier processing.
     let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
-    // Line-by-line processing is easiest.
-    let input = input.lines();
-    // Also get the dimension of the map.
-    let dim = args[2].parse::<usize>().unwrap();
 
     // --- TASK BEGIN ---
 
     // First, parse the whole file into a two-dimensional array.
-    let mut forest = Forest {
-        field: Vec::with_capacity(dim * dim),
-        dim,
-    };
-
-    // Simply iterate through all lines and characters.
-    for line in input {
-        for char in line.chars() {
-            // Convert the character value into the respective number.
-            forest.field.push(Tree::new(((char as u8) - b'0') as i8));
-        }
-    }
+    let mut forest = Forest::parse(&input).unwrap_or_else(|e| panic!("Invalid forest: {e}"));
 
     // Now that we have the data, go through each row and column twice.
     // In essence we place an observer at the top and bottom of every column
     // and an observer at the east and west end of every row.
     // Then, we check which trees are visible for that observer,
     // recording the result in `VisibleDirections`.
-    for i in 0..forest.dim {
+    // Columns and rows are swept separately, since the forest doesn't have to be square.
+    for x in 0..forest.width {
         // Initialize the variables keeping track of the largest tree encountered along the way.
         let mut max_n: i8 = -1;
         let mut max_s: i8 = -1;
-        let mut max_w: i8 = -1;
-        let mut max_e: i8 = -1;
 
-        for j in 0..forest.dim {
+        for j in 0..forest.height {
             // Get the current tree in this loop iteration as seen from the north.
-            let tree_n = forest.at(i, j);
+            let tree_n = forest.at(x, j);
             // Check if that tree is obscured from view and update its visibility.
             if tree_n.height <= max_n {
                 tree_n.visible_n = false;
@@ -113,21 +136,27 @@ fn main() {
             // Now repeat the exact same steps for the other three directions.
 
             // SOUTH
-            let tree_s = forest.at(i, forest.dim - j - 1);
+            let tree_s = forest.at(x, forest.height - j - 1);
             if tree_s.height <= max_s {
                 tree_s.visible_s = false;
             }
             max_s = std::cmp::max(max_s, tree_s.height);
+        }
+    }
+    for y in 0..forest.height {
+        let mut max_w: i8 = -1;
+        let mut max_e: i8 = -1;
 
+        for j in 0..forest.width {
             // WEST
-            let tree_w = forest.at(j, i);
+            let tree_w = forest.at(j, y);
             if tree_w.height <= max_w {
                 tree_w.visible_w = false;
             }
             max_w = std::cmp::max(max_w, tree_w.height);
 
             // EAST
-            let tree_e = forest.at(forest.dim - j - 1, i);
+            let tree_e = forest.at(forest.width - j - 1, y);
             if tree_e.height <= max_e {
                 tree_e.visible_e = false;
             }
@@ -137,8 +166,8 @@ fn main() {
 
     // Now, count the number of visible trees.
     let mut visible_count = 0;
-    for x in 0..forest.dim {
-        for y in 0..forest.dim {
+    for x in 0..forest.width {
+        for y in 0..forest.height {
             if forest.at(x, y).visible() {
                 visible_count += 1;
             }
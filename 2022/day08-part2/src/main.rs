@@ -1,3 +1,6 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
 // Custom data structure representing a single tree.
 // We store its height and keep track from which cardinal directions it is visible.
 #[derive(Debug, Copy, Clone)]
@@ -7,11 +10,11 @@ struct Tree {
     visible_s: bool,
     visible_w: bool,
     visible_e: bool,
-    viewdist_n: i8,
-    viewdist_s: i8,
-    viewdist_w: i8,
-    viewdist_e: i8,
-    scenic_score: i32,
+    viewdist_n: u32,
+    viewdist_s: u32,
+    viewdist_w: u32,
+    viewdist_e: u32,
+    scenic_score: u64,
 }
 
 impl Tree {
@@ -36,109 +39,214 @@ impl Tree {
     }
 }
 
+// Determine the viewing distance of every tree in a line, looking back towards its start.
+// A monotonic stack keeps the positions of all trees that could still block the view
+// of a later tree, which makes this O(n) for the whole line instead of O(n) per tree.
+fn viewing_distances(heights: impl Iterator<Item = i8>) -> Vec<u32> {
+    let mut distances = Vec::new();
+    // Positions of the trees on the stack, with strictly decreasing heights from bottom to top.
+    let mut stack: Vec<(usize, i8)> = Vec::new();
+    for (pos, height) in heights.enumerate() {
+        // Shorter trees can't block the view past the current tree, so they're done.
+        while stack.last().is_some_and(|&(_, h)| h < height) {
+            stack.pop();
+        }
+        // The view ends at the nearest tree at least as tall, or at the edge of the forest.
+        let distance = match stack.last() {
+            Some(&(blocker, _)) => pos - blocker,
+            None => pos,
+        };
+        distances.push(distance as u32);
+        stack.push((pos, height));
+    }
+    distances
+}
+
 // A custom struct for the whole forest.
 #[derive(Debug)]
 struct Forest {
     field: Vec<Tree>,
-    dim: usize,
+    width: usize,
+    height: usize,
 }
 
 impl Forest {
-    // Pretty printer for the forest, using terminal escape codes to color
-    // the hidden trees bold and red.
-    fn print(&self) {
-        for y in 0..self.dim {
-            for x in 0..self.dim {
-                let tree = self.field[y * self.dim + x];
-                if !tree.visible() {
-                    print!("\x1b[1;31m");
-                }
-                print!("{}", tree.height);
-                if !tree.visible() {
-                    print!("\x1b[0m");
-                }
+    // Parse the forest from the puzzle input, one row of digits per line.
+    // The dimensions are taken from the input itself, which has to be rectangular.
+    fn parse(input: &str) -> Result<Forest, String> {
+        let mut forest = Forest {
+            field: Vec::new(),
+            width: 0,
+            height: 0,
+        };
+
+        // Simply iterate through all lines and characters.
+        for (y, line) in input.lines().enumerate() {
+            // The first line determines the width, all others have to match it.
+            if y == 0 {
+                forest.width = line.len();
+            } else if line.len() != forest.width {
+                return Err(format!(
+                    "Line {} has {} trees, expected {} like the first line",
+                    y + 1,
+                    line.len(),
+                    forest.width
+                ));
+            }
+            for (x, char) in line.chars().enumerate() {
+                // Convert the character value into the respective number.
+                let height = char
+                    .to_digit(10)
+                    .ok_or_else(|| format!("Invalid tree height {char:?} at ({x},{y})"))?;
+                forest.field.push(Tree::new(height as i8));
             }
-            println!();
+            forest.height += 1;
+        }
+
+        if forest.field.is_empty() {
+            return Err(String::from("The forest is empty"));
         }
+        Ok(forest)
     }
 
+    // Print the scenic scores as a table, padding every score to the same width.
     fn print_scenic_score(&self) {
-        for y in 0..self.dim {
-            for x in 0..self.dim {
-                let tree = self.field[y * self.dim + x];
-                // if !tree.visible() {
-                //     print!("\x1b[1;31m");
-                // }
-                print!("{}", tree.scenic_score);
-                // if !tree.visible() {
-                //     print!("\x1b[0m");
-                // }
-            }
-            println!();
+        let max = self.field.iter().map(|t| t.scenic_score).max().unwrap_or(0);
+        let width = max.to_string().len();
+        for row in self.field.chunks(self.width) {
+            let line = row
+                .iter()
+                .map(|tree| format!("{:>width$}", tree.scenic_score))
+                .collect::<Vec<_>>();
+            println!("{}", line.join(" "));
         }
     }
 
-    // Easy accessor for a tree using x and y coordintes.
-    fn at(&mut self, x: usize, y: usize) -> &mut Tree {
-        &mut self.field[y * self.dim + x]
+    // Export three images of the forest, each using one pixel (or square of pixels) per tree:
+    // - `<prefix>-height.ppm`: The tree heights, from black (0) to white (9).
+    // - `<prefix>-visibility.ppm`: From which directions a tree can be seen.
+    //   Red marks visibility from the west (dark, 90) and/or east (bright, 165, or 255
+    //   for both), green the same for north and south. Hidden trees are dark blue.
+    // - `<prefix>-scenic.ppm`: The scenic scores on a logarithmic heat scale.
+    // The tree with the best scenic score is marked with a cyan crosshair on all three images.
+    fn export_images(&self, prefix: &str, best: usize) -> std::io::Result<()> {
+        // Blow up small forests so the images can actually be looked at.
+        let scale = (512 / self.width.max(self.height)).max(1);
+        let max_score = self.field.iter().map(|t| t.scenic_score).max().unwrap_or(0);
+
+        let height_map = |t: &Tree| {
+            let v = (t.height as u32 * 255 / 9) as u8;
+            [v, v, v]
+        };
+        let visibility_map = |t: &Tree| {
+            if !t.visible() {
+                return [0, 0, 96];
+            }
+            let r = 90 * t.visible_w as u8 + 165 * t.visible_e as u8;
+            let g = 90 * t.visible_n as u8 + 165 * t.visible_s as u8;
+            [r, g, 0]
+        };
+        let scenic_map = |t: &Tree| {
+            // Scores span several orders of magnitude, so use a logarithmic scale.
+            let v = ((t.scenic_score as f64).ln_1p() / (max_score as f64).ln_1p().max(1.0)) * 3.0;
+            // Black -> red -> yellow -> white.
+            let channel = |offset: f64| ((v - offset).clamp(0.0, 1.0) * 255.0) as u8;
+            [channel(0.0), channel(1.0), channel(2.0)]
+        };
+
+        self.write_ppm(&format!("{prefix}-height.ppm"), scale, best, height_map)?;
+        self.write_ppm(
+            &format!("{prefix}-visibility.ppm"),
+            scale,
+            best,
+            visibility_map,
+        )?;
+        self.write_ppm(&format!("{prefix}-scenic.ppm"), scale, best, scenic_map)?;
+        Ok(())
     }
 
-    // Easy accessor using x and y coordiantes that's allowed to fail
-    // if the coordinates are out-of-bounds.
-    fn ato(&mut self, x: isize, y: isize) -> Option<&mut Tree> {
-        if x < 0 || y < 0 || x >= (self.dim as isize) || y >= (self.dim as isize) {
-            None
-        } else {
-            Some(&mut self.field[(y * (self.dim as isize) + x) as usize])
+    // Write a binary PPM image, coloring every tree using the given function.
+    fn write_ppm(
+        &self,
+        path: &str,
+        scale: usize,
+        best: usize,
+        color: impl Fn(&Tree) -> [u8; 3],
+    ) -> std::io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        // Size the crosshair relative to the forest, so it stays visible on large maps.
+        let (best_x, best_y) = (best % self.width, best / self.width);
+        let radius = self.width.max(self.height) / 50 + 1;
+        let marked = |x: usize, y: usize| {
+            (x == best_x && y.abs_diff(best_y) <= radius)
+                || (y == best_y && x.abs_diff(best_x) <= radius)
+        };
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            self.width * scale,
+            self.height * scale
+        )?;
+        for (y, row) in self.field.chunks(self.width).enumerate() {
+            // Every row of trees is repeated `scale` times, and so is every tree within it.
+            let mut line = Vec::with_capacity(self.width * scale * 3);
+            for (x, tree) in row.iter().enumerate() {
+                let pixel = if marked(x, y) {
+                    [0, 255, 255]
+                } else {
+                    color(tree)
+                };
+                for _ in 0..scale {
+                    line.extend_from_slice(&pixel);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
         }
+        out.flush()
+    }
+
+    // Easy accessor for a tree using x and y coordintes.
+    fn at(&mut self, x: usize, y: usize) -> &mut Tree {
+        &mut self.field[y * self.width + x]
     }
 }
 
 fn main() {
     // Use command line arguments to specify the input filename.
     let args: Vec<String> = std::env::args().collect();
-    if args.len() < 3 {
-        panic!("Usage: ./main <input-file> <map-dimensions>\nNot enough arguments. Exiting.");
+    if args.len() < 2 {
+        panic!("Usage: ./main <input-file> [--export <prefix>]\nNo input file provided. Exiting.");
     }
+    // With --export, write images of the forest instead of printing the scenic scores.
+    let export_prefix = args.iter().position(|a| a == "--export").map(|idx| {
+        args.get(idx + 1)
+            .expect("--export requires a prefix for the image files")
+    });
 
     // Next, read the contents of the input file into a string for easier processing.
     let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
-    // Line-by-line processing is easiest.
-    let input = input.lines();
-    // Also get the dimension of the map.
-    let dim = args[2].parse::<usize>().unwrap();
 
     // --- TASK BEGIN ---
 
     // First, parse the whole file into a two-dimensional array.
-    let mut forest = Forest {
-        field: Vec::with_capacity(dim * dim),
-        dim,
-    };
-
-    // Simply iterate through all lines and characters.
-    for line in input {
-        for char in line.chars() {
-            // Convert the character value into the respective number.
-            forest.field.push(Tree::new(((char as u8) - b'0') as i8));
-        }
-    }
+    let mut forest = Forest::parse(&input).unwrap_or_else(|e| panic!("Invalid forest: {e}"));
 
     // Now that we have the data, go through each row and column twice.
     // In essence we place an observer at the top and bottom of every column
     // and an observer at the east and west end of every row.
     // Then, we check which trees are visible for that observer,
     // recording the result in `VisibleDirections`.
-    for i in 0..forest.dim {
+    // Columns and rows are swept separately, since the forest doesn't have to be square.
+    for x in 0..forest.width {
         // Initialize the variables keeping track of the largest tree encountered along the way.
         let mut max_n: i8 = -1;
         let mut max_s: i8 = -1;
-        let mut max_w: i8 = -1;
-        let mut max_e: i8 = -1;
 
-        for j in 0..forest.dim {
+        for j in 0..forest.height {
             // Get the current tree in this loop iteration as seen from the north.
-            let tree_n = forest.at(i, j);
+            let tree_n = forest.at(x, j);
             // Check if that tree is obscured from view and update its visibility.
             if tree_n.height <= max_n {
                 tree_n.visible_n = false;
@@ -149,21 +257,27 @@ fn main() {
             // Now repeat the exact same steps for the other three directions.
 
             // SOUTH
-            let tree_s = forest.at(i, forest.dim - j - 1);
+            let tree_s = forest.at(x, forest.height - j - 1);
             if tree_s.height <= max_s {
                 tree_s.visible_s = false;
             }
             max_s = std::cmp::max(max_s, tree_s.height);
+        }
+    }
+    for y in 0..forest.height {
+        let mut max_w: i8 = -1;
+        let mut max_e: i8 = -1;
 
+        for j in 0..forest.width {
             // WEST
-            let tree_w = forest.at(j, i);
+            let tree_w = forest.at(j, y);
             if tree_w.height <= max_w {
                 tree_w.visible_w = false;
             }
             max_w = std::cmp::max(max_w, tree_w.height);
 
             // EAST
-            let tree_e = forest.at(forest.dim - j - 1, i);
+            let tree_e = forest.at(forest.width - j - 1, y);
             if tree_e.height <= max_e {
                 tree_e.visible_e = false;
             }
@@ -173,8 +287,8 @@ fn main() {
 
     // Now, count the number of visible trees.
     let mut visible_count = 0;
-    for x in 0..forest.dim {
-        for y in 0..forest.dim {
+    for x in 0..forest.width {
+        for y in 0..forest.height {
             if forest.at(x, y).visible() {
                 visible_count += 1;
             }
@@ -182,89 +296,72 @@ fn main() {
     }
 
     // PART TWO
-    // Calculate the visibility score for every tree.
-    let mut best_scenic_score: i32 = 0;
-    for x in 0..forest.dim {
-        for y in 0..forest.dim {
-            // Truly not the cleanest way to go about this.
-            // Better would be an enum for all directions.
-            // Iterate over all four cardinal directions.
-            for dir in 0..4 {
-                let current_height: i8 = forest.at(x, y).height;
-
-                let mut walking_distance: isize = 1;
-                loop {
-                    // if x == 2 && y == 1 && dir == 2 {
-                    //     println!("walkdist = {}", walking_distance);
-                    //     dbg!(&forest.at(x, y));
-                    // }
-                    // Get the tree we're currently looking at.
-                    // This depends on the direction we're currently looking at.
-                    let tree = match dir {
-                        0 => forest.ato(x as isize, (y as isize) - walking_distance), // north
-                        1 => forest.ato(x as isize, (y as isize) + walking_distance), // south
-                        2 => forest.ato((x as isize) + walking_distance, y as isize), // east
-                        _ => forest.ato((x as isize) - walking_distance, y as isize), // west
-                    };
-                    match tree {
-                        // Invalid coordinate? We're done already.
-                        None => {
-                            // if x == 2 && y == 1 && dir == 2 {
-                            //     println!("NONE!");
-                            //     println!("walkdist = {}", walking_distance);
-                            //     dbg!(&forest.at(x, y));
-                            // }
-                            break;
-                        }
-                        // Something here? Check for its height.
-                        Some(tree) => {
-                            // We can see this tree, so add it to the count.
-                            walking_distance += 1;
-                            if tree.height >= current_height {
-                                // Too tall? We're done counting then.
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                // if x == 2 && y == 1 && dir == 2 {
-                //     println!("walkdist = {}", walking_distance);
-                //     dbg!(&forest.at(x, y));
-                // }
-
-                // Finally, set the tree distance.
-                match dir {
-                    0 => {
-                        forest.at(x, y).viewdist_n = (walking_distance - 1) as i8;
-                    }
-                    1 => {
-                        forest.at(x, y).viewdist_s = (walking_distance - 1) as i8;
-                    }
-                    2 => {
-                        forest.at(x, y).viewdist_e = (walking_distance - 1) as i8;
-                    }
-                    _ => {
-                        forest.at(x, y).viewdist_w = (walking_distance - 1) as i8;
-                    }
-                }
-            }
-
-            // Finally, calculate the tree's scenic score.
-            let mut scenic_score: i32 = 1;
-            scenic_score *= forest.at(x, y).viewdist_n as i32;
-            scenic_score *= forest.at(x, y).viewdist_s as i32;
-            scenic_score *= forest.at(x, y).viewdist_e as i32;
-            scenic_score *= forest.at(x, y).viewdist_w as i32;
-            forest.at(x, y).scenic_score = scenic_score;
-
-            best_scenic_score = std::cmp::max(best_scenic_score, scenic_score);
+    // Calculate the viewing distances with one sweep per row and column and direction.
+    for x in 0..forest.width {
+        // NORTH
+        let column = (0..forest.height)
+            .map(|y| forest.at(x, y).height)
+            .collect::<Vec<_>>();
+        for (y, d) in viewing_distances(column.iter().copied())
+            .into_iter()
+            .enumerate()
+        {
+            forest.at(x, y).viewdist_n = d;
+        }
+        // SOUTH
+        let distances = viewing_distances(column.iter().rev().copied());
+        for (j, d) in distances.into_iter().enumerate() {
+            forest.at(x, forest.height - j - 1).viewdist_s = d;
+        }
+    }
+    for y in 0..forest.height {
+        // WEST
+        let row = (0..forest.width)
+            .map(|x| forest.at(x, y).height)
+            .collect::<Vec<_>>();
+        for (x, d) in viewing_distances(row.iter().copied())
+            .into_iter()
+            .enumerate()
+        {
+            forest.at(x, y).viewdist_w = d;
+        }
+        // EAST
+        let distances = viewing_distances(row.iter().rev().copied());
+        for (j, d) in distances.into_iter().enumerate() {
+            forest.at(forest.width - j - 1, y).viewdist_e = d;
+        }
+    }
 
-            // println!("({},{},{})", x, y, scenic_score);
+    // Finally, calculate every tree's scenic score.
+    let mut best_scenic_score: u64 = 0;
+    let mut best_tree: usize = 0;
+    for (idx, tree) in forest.field.iter_mut().enumerate() {
+        tree.scenic_score = tree.viewdist_n as u64
+            * tree.viewdist_s as u64
+            * tree.viewdist_e as u64
+            * tree.viewdist_w as u64;
+        if tree.scenic_score > best_scenic_score {
+            best_scenic_score = tree.scenic_score;
+            best_tree = idx;
         }
     }
 
-    // Print the forest's scenic scores and the best scenic score.
-    forest.print_scenic_score();
-    println!("Best scenic score: {}", best_scenic_score);
+    // Either export the images or print the forest's scenic scores.
+    match export_prefix {
+        Some(prefix) => {
+            forest
+                .export_images(prefix, best_tree)
+                .expect("Error writing images");
+            println!("Exported images to {prefix}-*.ppm");
+        }
+        None => forest.print_scenic_score(),
+    }
+    // Then print the number of visible trees and the best scenic score.
+    println!("Total trees visible: {}", visible_count);
+    println!(
+        "Best scenic score: {} at ({},{})",
+        best_scenic_score,
+        best_tree % forest.width,
+        best_tree / forest.width
+    );
 }
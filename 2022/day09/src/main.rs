@@ -0,0 +1,462 @@
+use aoc_intervals::{Interval, IntervalSet};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[derive(Copy, Clone, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+use Direction::*;
+
+impl Direction {
+    fn from_char(dir: char) -> Direction {
+        match dir {
+            'R' => Right,
+            'L' => Left,
+            'U' => Up,
+            'D' => Down,
+            _ => {
+                panic!("Invalid character to construct Direction form");
+            }
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Right => 'R',
+            Left => 'L',
+            Up => 'U',
+            Down => 'D',
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+impl Coord {
+    fn new() -> Coord {
+        Coord { x: 0, y: 0 }
+    }
+
+    fn move_towards(&mut self, dir: Direction) {
+        *self = self.offset(dir, 1);
+    }
+
+    // The coordinate `distance` steps away in the given direction.
+    fn offset(&self, dir: Direction, distance: i64) -> Coord {
+        match dir {
+            Up => Coord {
+                x: self.x,
+                y: self.y + distance,
+            },
+            Down => Coord {
+                x: self.x,
+                y: self.y - distance,
+            },
+            Left => Coord {
+                x: self.x - distance,
+                y: self.y,
+            },
+            Right => Coord {
+                x: self.x + distance,
+                y: self.y,
+            },
+        }
+    }
+
+    fn correct_tail(&mut self, head: Coord) {
+        let dx = head.x - self.x;
+        let dy = head.y - self.y;
+
+        // Do nothing if there isn't any actual distance between
+        // the tail and head, i.e. at least one of the dimensions
+        // has distance value 2 or greater.
+        if dx.abs() > 1 || dy.abs() > 1 {
+            // Use the sign of the delta to move the tail towards the head.
+            // This automatically takes care of the diagonal vs. horizontal vs. vertical
+            // behvaior outlined in the task description.
+            self.x += dx.signum();
+            self.y += dy.signum();
+        }
+    }
+}
+
+// The smallest and largest coordinates of the given set, i.e. its bounding box.
+fn bounds<'a>(coords: impl Iterator<Item = &'a Coord>) -> (Coord, Coord) {
+    coords.fold((Coord::new(), Coord::new()), |(min, max), c| {
+        (
+            Coord {
+                x: min.x.min(c.x),
+                y: min.y.min(c.y),
+            },
+            Coord {
+                x: max.x.max(c.x),
+                y: max.y.max(c.y),
+            },
+        )
+    })
+}
+
+// All the coordinates visited by a knot.
+// Rather than storing every cell on its own, the visited cells are kept as intervals:
+// horizontal stretches per row and vertical stretches per column, so a long move in
+// any direction only takes up a single entry. A cell may be part of both a row and a
+// column interval, which the count takes care of.
+struct Trail {
+    rows: BTreeMap<i64, IntervalSet>,
+    columns: BTreeMap<i64, IntervalSet>,
+}
+
+impl Trail {
+    fn new() -> Trail {
+        Trail {
+            rows: BTreeMap::new(),
+            columns: BTreeMap::new(),
+        }
+    }
+
+    fn insert(&mut self, c: Coord) {
+        self.rows
+            .entry(c.y)
+            .or_default()
+            .insert(Interval::single(c.x));
+    }
+
+    // Insert all cells on the straight line between the two coordinates, both included.
+    fn insert_line(&mut self, from: Coord, to: Coord) {
+        if from.y == to.y {
+            let interval = Interval::new(from.x.min(to.x), from.x.max(to.x));
+            self.rows.entry(from.y).or_default().insert(interval);
+        } else {
+            let interval = Interval::new(from.y.min(to.y), from.y.max(to.y));
+            self.columns.entry(from.x).or_default().insert(interval);
+        }
+    }
+
+    // The total number of visited cells.
+    fn len(&self) -> u64 {
+        let in_rows: u64 = self.rows.values().map(|r| r.len()).sum();
+        let in_columns: u64 = self.columns.values().map(|c| c.len()).sum();
+        // Subtract the cells that were counted in both a row and a column.
+        let in_both = self
+            .columns
+            .iter()
+            .flat_map(|(&x, column)| {
+                column.intervals().iter().map(move |interval| {
+                    self.rows
+                        .range(interval.start()..=interval.end())
+                        .filter(|(_, row)| row.contains(x))
+                        .count() as u64
+                })
+            })
+            .sum::<u64>();
+        in_rows + in_columns - in_both
+    }
+
+    // All rows with their visited intervals, sorted from top to bottom.
+    fn sorted_rows(&self) -> impl Iterator<Item = (i64, &IntervalSet)> {
+        self.rows.iter().rev().map(|(&y, r)| (y, r))
+    }
+
+    // All columns with their visited intervals, sorted from left to right.
+    fn sorted_columns(&self) -> impl Iterator<Item = (i64, &IntervalSet)> {
+        self.columns.iter().map(|(&x, c)| (x, c))
+    }
+
+    // The bounding box of all visited cells.
+    fn bounds(&self) -> (Coord, Coord) {
+        let row_corners = self
+            .sorted_rows()
+            .filter(|(_, r)| !r.is_empty())
+            .flat_map(|(y, r)| {
+                let intervals = r.intervals();
+                [
+                    Coord {
+                        x: intervals[0].start(),
+                        y,
+                    },
+                    Coord {
+                        x: intervals[intervals.len() - 1].end(),
+                        y,
+                    },
+                ]
+            });
+        let column_corners = self
+            .sorted_columns()
+            .filter(|(_, c)| !c.is_empty())
+            .flat_map(|(x, c)| {
+                let intervals = c.intervals();
+                [
+                    Coord {
+                        x,
+                        y: intervals[0].start(),
+                    },
+                    Coord {
+                        x,
+                        y: intervals[intervals.len() - 1].end(),
+                    },
+                ]
+            });
+        let corners = row_corners.chain(column_corners).collect::<Vec<_>>();
+        bounds(corners.iter())
+    }
+}
+
+// A rope consisting of a head and any number of knots following it.
+// Every knot keeps track of all the coordinates it has visited.
+struct Rope {
+    knots: Vec<Coord>,
+    visited: Vec<Trail>,
+    // If recording, the direction of every step together with the resulting knot positions.
+    frames: Option<Vec<(Direction, Vec<Coord>)>>,
+}
+
+impl Rope {
+    // Create a rope with the given number of knots, all starting at the origin.
+    fn new(knot_count: usize) -> Rope {
+        let knots = vec![Coord::new(); knot_count];
+        let visited = knots
+            .iter()
+            .map(|k| {
+                let mut trail = Trail::new();
+                trail.insert(*k);
+                trail
+            })
+            .collect();
+        Rope {
+            knots,
+            visited,
+            frames: None,
+        }
+    }
+
+    // Start recording the rope's position after every step.
+    fn record(&mut self) {
+        self.frames.get_or_insert_with(Vec::new);
+    }
+
+    // Move the whole rope towards a direction.
+    fn move_rope(&mut self, dir: Direction) {
+        // Move the head in the given direction.
+        self.knots[0].move_towards(dir);
+        // Then iterate over all remaining "links" of the rope, starting from the head.
+        for i in 1..self.knots.len() {
+            // Copy the destination coordinate.
+            let prev = self.knots[i - 1];
+            // And then correct the current link towards that previous one.
+            self.knots[i].correct_tail(prev);
+        }
+        // Finally, store the new coordinate of every knot.
+        for (knot, visited) in self.knots.iter().zip(self.visited.iter_mut()) {
+            visited.insert(*knot);
+        }
+        if let Some(frames) = &mut self.frames {
+            frames.push((dir, self.knots.clone()));
+        }
+    }
+
+    // Move the whole rope `distance` steps towards a direction.
+    // Once the rope has been pulled into a straight line behind the head, every knot
+    // simply follows the head step for step, so the remaining distance can be covered
+    // in one go, adding each knot's trail as a whole segment.
+    fn move_rope_by(&mut self, dir: Direction, distance: i64) {
+        let mut remaining = distance;
+        // Recording needs every single step, so there's no shortcut then.
+        while remaining > 0 && (self.frames.is_some() || !self.is_straight(dir)) {
+            self.move_rope(dir);
+            remaining -= 1;
+        }
+        if remaining == 0 {
+            return;
+        }
+        for (knot, trail) in self.knots.iter_mut().zip(self.visited.iter_mut()) {
+            let end = knot.offset(dir, remaining);
+            trail.insert_line(*knot, end);
+            *knot = end;
+        }
+    }
+
+    // Check whether every knot sits directly behind the previous one, as seen from the direction.
+    fn is_straight(&self, dir: Direction) -> bool {
+        self.knots
+            .windows(2)
+            .all(|pair| pair[1] == pair[0].offset(dir, -1))
+    }
+
+    // Render all recorded steps as ASCII frames, in the style of the puzzle's own diagrams:
+    // `H` is the head, followed by the knots `1`, `2`, ... (or `T` for a two-knot rope),
+    // `s` is the starting position and `.` is empty space.
+    // All frames share the same bounding box, so they can be flipped through like an animation.
+    fn format_frames(&self) -> String {
+        let frames = self.frames.as_deref().unwrap_or_default();
+        let (min, max) = bounds(frames.iter().flat_map(|(_, knots)| knots));
+
+        let mut out = String::new();
+        for (step, (dir, knots)) in frames.iter().enumerate() {
+            writeln!(out, "== Step {}: {} ==", step + 1, dir.to_char()).unwrap();
+            // Rows are printed top-down, i.e. with descending y-coordinates.
+            for y in (min.y..=max.y).rev() {
+                for x in min.x..=max.x {
+                    // Knots in front cover the ones behind them.
+                    let c = match knots.iter().position(|k| k.x == x && k.y == y) {
+                        Some(0) => 'H',
+                        Some(1) if knots.len() == 2 => 'T',
+                        Some(idx) => char::from_digit(idx as u32 % 36, 36).unwrap(),
+                        None if x == 0 && y == 0 => 's',
+                        None => '.',
+                    };
+                    out.push(c);
+                }
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    // Render the cells visited by the tail as an SVG image over their bounding box.
+    // The starting position is outlined in red.
+    fn format_tail_svg(&self) -> String {
+        const CELL: i64 = 10;
+        let visited = self.visited.last().unwrap();
+        let (min, max) = visited.bounds();
+        let width = (max.x - min.x + 1) * CELL;
+        let height = (max.y - min.y + 1) * CELL;
+        // SVG's y-axis points downwards, so flip the coordinates.
+        let corner = |c: &Coord| ((c.x - min.x) * CELL, (max.y - c.y) * CELL);
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )
+        .unwrap();
+        writeln!(
+            out,
+            r#"<rect width="{width}" height="{height}" fill="white"/>"#
+        )
+        .unwrap();
+        // Every visited interval of a row becomes a single rectangle.
+        for (y, row) in visited.sorted_rows() {
+            for interval in row.intervals() {
+                let (x, y) = corner(&Coord {
+                    x: interval.start(),
+                    y,
+                });
+                let w = interval.len() as i64 * CELL;
+                writeln!(
+                    out,
+                    r#"<rect x="{x}" y="{y}" width="{w}" height="{CELL}" fill="steelblue"/>"#
+                )
+                .unwrap();
+            }
+        }
+        // And every visited interval of a column as well.
+        for (x, column) in visited.sorted_columns() {
+            for interval in column.intervals() {
+                let (x, y) = corner(&Coord {
+                    x,
+                    y: interval.end(),
+                });
+                let h = interval.len() as i64 * CELL;
+                writeln!(
+                    out,
+                    r#"<rect x="{x}" y="{y}" width="{CELL}" height="{h}" fill="steelblue"/>"#
+                )
+                .unwrap();
+            }
+        }
+        let (x, y) = corner(&Coord::new());
+        writeln!(
+            out,
+            r#"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" fill="none" stroke="red" stroke-width="2"/>"#
+        )
+        .unwrap();
+        out.push_str("</svg>\n");
+        out
+    }
+
+    // The number of coordinates visited by each knot, starting with the head.
+    fn visited_counts(&self) -> Vec<u64> {
+        self.visited.iter().map(|v| v.len()).collect()
+    }
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [knot-count] [--frames <file>] [--svg <file>]\nNo input file provided. Exiting."
+        );
+    }
+
+    // Optionally export an ASCII animation of the rope and/or an SVG of the tail's trail.
+    let mut knot_count = 10;
+    let mut frames_file = None;
+    let mut svg_file = None;
+    let mut rest = args[2..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--frames" => frames_file = Some(rest.next().expect("--frames requires a filename")),
+            "--svg" => svg_file = Some(rest.next().expect("--svg requires a filename")),
+            // The number of knots, including the head. Part two needs ten of them.
+            count => knot_count = count.parse::<usize>().expect("Invalid knot count"),
+        }
+    }
+    if knot_count == 0 {
+        panic!("The rope needs at least one knot");
+    }
+
+    // Next, read the contents of the input file into a string for easier processing.
+    let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
+    // Line-by-line processing is easiest.
+    let input = input.lines();
+
+    // --- TASK BEGIN ---
+
+    // Keep track of the rope as a series of coordinates.
+    let mut rope = Rope::new(knot_count);
+    if frames_file.is_some() {
+        rope.record();
+    }
+
+    // Go through the instructions line-by-line.
+    for line in input {
+        // Split and parse each line into direction (char) and distance (i64).
+        let s: Vec<_> = line.split(' ').collect();
+        let direction = Direction::from_char(s[0].chars().next().unwrap());
+        let distance = s[1].parse::<i64>().unwrap();
+
+        // Then move the head `distance` times, dragging the rest of the rope along.
+        rope.move_rope_by(direction, distance);
+    }
+
+    // Every knot follows the one in front of it just like the tail of a two-knot rope would.
+    // Knot 1 therefore answers part one, while knot 9 answers part two.
+    let counts = rope.visited_counts();
+    for (idx, count) in counts.iter().enumerate() {
+        println!("Knot {idx}: {count} visited coordinates");
+    }
+    if let Some(count) = counts.get(1) {
+        println!("Part 1 (knot 1): {count}");
+    }
+    if let Some(count) = counts.get(9) {
+        println!("Part 2 (knot 9): {count}");
+    }
+
+    if let Some(file) = frames_file {
+        std::fs::write(file, rope.format_frames()).expect("Error writing frames");
+    }
+    if let Some(file) = svg_file {
+        std::fs::write(file, rope.format_tail_svg()).expect("Error writing SVG");
+    }
+}
@@ -0,0 +1,332 @@
+// The shape of the display and how the CPU's signal is sampled.
+#[derive(Debug, Copy, Clone)]
+struct DisplayConfig {
+    // Screen dimensions in pixels. One pixel is drawn per cycle, row by row.
+    width: usize,
+    height: usize,
+    // The number of pixels covered by the sprite, centered around X.
+    sprite_width: usize,
+    // The signal strength is sampled at cycle `sample_start` and every `sample_step` cycles after.
+    sample_start: usize,
+    sample_step: usize,
+}
+
+impl DisplayConfig {
+    // The handheld's 40x6 display with its 3 pixel wide sprite, sampled at cycles 20, 60, 100, ...
+    fn handheld() -> DisplayConfig {
+        DisplayConfig {
+            width: 40,
+            height: 6,
+            sprite_width: 3,
+            sample_start: 20,
+            sample_step: 40,
+        }
+    }
+
+    // Check whether the sprite at position X covers the given column.
+    // For the default width of three, that's X-1, X and X+1.
+    fn sprite_covers(&self, x: i32, col: i32) -> bool {
+        let left = x - (self.sprite_width as i32 - 1) / 2;
+        let right = x + self.sprite_width as i32 / 2;
+        left <= col && col <= right
+    }
+}
+
+struct Crt {
+    config: DisplayConfig,
+    screen: Vec<bool>,
+}
+
+impl Crt {
+    fn new(config: DisplayConfig) -> Crt {
+        Crt {
+            config,
+            screen: vec![false; config.width * config.height],
+        }
+    }
+
+    fn process_cycle(&mut self, cycle: usize, x: i32) {
+        // Cycles beyond the last pixel don't draw anything anymore.
+        if cycle >= self.screen.len() {
+            return;
+        }
+        // Split cycle into corresponding line and column.
+        let col = (cycle % self.config.width) as i32;
+
+        // Set the current pixel if the current column and the
+        // sprite painted by the current x value overlap.
+        self.screen[cycle] = self.config.sprite_covers(x, col);
+    }
+
+    fn print(&self) {
+        for row in self.screen.chunks(self.config.width) {
+            // Print `##` or `. ` depending on bool value.
+            for pixel in row {
+                print!("{}", if *pixel { "##" } else { ". " });
+            }
+            println!();
+        }
+    }
+}
+
+// Run the program and record the value of X *during* every cycle.
+fn trace(program: &str) -> Vec<i32> {
+    // Keep track of the current value of X for all the instructions.
+    // We'll use one massive vector for that purpose.
+    let mut x_over_time: Vec<i32> = Vec::with_capacity(512);
+
+    // Keep track of the actual x as well.
+    let mut x = 1;
+
+    // Then, process line-by-line.
+    for line in program.lines() {
+        // Split by space.
+        let line: Vec<_> = line.split(' ').collect();
+
+        // Differentiate by instruction
+        match line[0] {
+            "noop" => {
+                // Nothing changes.
+                x_over_time.push(x);
+            }
+            "addx" => {
+                // Addition is complete *after* two cycles.
+                // So during those two cycles x has the old value still.
+                x_over_time.push(x);
+                x_over_time.push(x);
+                // Of course, afterwards the value of x is updated.
+                x += line[1].parse::<i32>().unwrap();
+            }
+            _ => (),
+        }
+    }
+    x_over_time
+}
+
+// Print the program with the cycles each instruction occupies,
+// the value of X during those cycles and the value of X afterwards.
+fn annotate(program: &str) {
+    let mut cycle = 1;
+    let mut x = 1;
+    for line in program.lines() {
+        let words: Vec<_> = line.split(' ').collect();
+        let (cycles, next_x) = match words[0] {
+            "noop" => (1, x),
+            "addx" => (2, x + words[1].parse::<i32>().unwrap()),
+            _ => {
+                println!("{:>15}  {line}  (ignored)", "");
+                continue;
+            }
+        };
+        let span = if cycles == 1 {
+            format!("{cycle}")
+        } else {
+            format!("{cycle}-{}", cycle + 1)
+        };
+        println!("cycle {span:>9}  X={x:<4}  {line:<10}  => X={next_x}");
+        cycle += cycles;
+        x = next_x;
+    }
+}
+
+// Parse a bitmap of `#` (lit) and `.` (dark) pixels, returning its pixels and width.
+fn parse_bitmap(input: &str) -> (Vec<bool>, usize) {
+    let rows = input.lines().filter(|l| !l.is_empty()).collect::<Vec<_>>();
+    let width = rows.first().map_or(0, |r| r.len());
+    let mut pixels = Vec::with_capacity(width * rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            panic!("Row {} of the bitmap isn't {width} pixels wide", y + 1);
+        }
+        pixels.extend(row.chars().map(|c| match c {
+            '#' => true,
+            '.' => false,
+            _ => panic!("Invalid pixel {c:?} in the bitmap, use '#' or '.'"),
+        }));
+    }
+    (pixels, width)
+}
+
+// Find a program with as few instructions as possible that draws the given pixels.
+// Every cycle draws one pixel, and X can only change at the end of an `addx`,
+// after staying the same for both of its cycles. Since the number of cycles is fixed,
+// the shortest program is the one using the most `addx`es, which is found with a
+// dynamic program over (cycle, X). Returns None if the bitmap can't be drawn at all.
+fn assemble(pixels: &[bool], config: &DisplayConfig) -> Option<Vec<String>> {
+    // Positions further left or right than this all look the same, since the sprite is off-screen.
+    let lo = -(config.sprite_width as i32) - 1;
+    let hi = (config.width + config.sprite_width) as i32 + 1;
+    let xs = (hi - lo + 1) as usize;
+    let n = pixels.len();
+    // Check whether X draws the pixel at cycle c correctly. Cycles past the screen don't matter.
+    let draws = |c: usize, x: i32| {
+        c >= n || pixels[c] == config.sprite_covers(x, (c % config.width) as i32)
+    };
+
+    // best[c][x] holds the fewest instructions needed to correctly draw the first c pixels
+    // and arrive at cycle c with the given X, together with the previous state.
+    const NONE: (usize, usize, usize) = (usize::MAX, 0, 0);
+    let mut best = vec![vec![NONE; xs]; n + 2];
+    // Initially, X is 1. Clamp it into the considered range, in case the sprite is really wide.
+    let start = (1 - lo).clamp(0, xs as i32 - 1) as usize;
+    best[0][start] = (0, 0, 0);
+    for c in 0..n {
+        for xi in 0..xs {
+            let (count, _, _) = best[c][xi];
+            if count == usize::MAX {
+                continue;
+            }
+            let x = lo + xi as i32;
+            if !draws(c, x) {
+                continue;
+            }
+            // noop: Keeps X for the next cycle.
+            if count + 1 < best[c + 1][xi].0 {
+                best[c + 1][xi] = (count + 1, c, xi);
+            }
+            // addx: Keeps X for another cycle and then jumps to any X.
+            if draws(c + 1, x) {
+                for entry in best[c + 2].iter_mut() {
+                    if count + 1 < entry.0 {
+                        *entry = (count + 1, c, xi);
+                    }
+                }
+            }
+        }
+    }
+
+    // The program may end early, as long as all the remaining pixels are dark.
+    // Undrawn pixels simply stay dark.
+    let first_end = pixels
+        .iter()
+        .rposition(|&p| p)
+        .map_or(0, |last_lit| last_lit + 1);
+    let end = (first_end..=n + 1)
+        .flat_map(|c| (0..xs).map(move |xi| (c, xi)))
+        .filter(|&(c, xi)| best[c][xi].0 != usize::MAX)
+        .min_by_key(|&(c, xi)| best[c][xi].0);
+
+    // Walk back from the end to reconstruct the instructions.
+    let (mut c, mut xi) = end?;
+    let mut program = Vec::new();
+    while c > 0 {
+        let (_, pc, pxi) = best[c][xi];
+        if c - pc == 1 {
+            program.push(String::from("noop"));
+        } else {
+            program.push(format!("addx {}", xi as i32 - pxi as i32));
+        }
+        (c, xi) = (pc, pxi);
+    }
+    program.reverse();
+    Some(program)
+}
+
+// Compute the sum of the signal strengths at all sampled cycles. (part one)
+fn signal_strength(x_over_time: &[i32], config: &DisplayConfig) -> i64 {
+    // Cycles are counted starting from 1, while the trace starts at index 0.
+    x_over_time
+        .iter()
+        .enumerate()
+        .skip(config.sample_start.saturating_sub(1))
+        .step_by(config.sample_step)
+        .map(|(i, x)| (i as i64 + 1) * *x as i64)
+        .sum()
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [--assemble | --annotate] [--width <n>] [--height <n>] [--sprite <n>] [--sample-start <n>] [--sample-step <n>]\nNo input file provided. Exiting."
+        );
+    }
+
+    // Everything about the display can be overridden, defaulting to the handheld's.
+    let mut config = DisplayConfig::handheld();
+    // With --assemble, the input is a bitmap to turn into a program drawing it.
+    // With --annotate, the input program is printed along with its cycles and values of X.
+    let mut assemble_mode = false;
+    let mut annotate_mode = false;
+    let mut rest = args[2..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--assemble" => {
+                assemble_mode = true;
+                continue;
+            }
+            "--annotate" => {
+                annotate_mode = true;
+                continue;
+            }
+            _ => (),
+        }
+        let value = rest
+            .next()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or_else(|| panic!("{arg} requires a positive integer"));
+        match arg.as_str() {
+            "--width" => config.width = value,
+            "--height" => config.height = value,
+            "--sprite" => config.sprite_width = value,
+            "--sample-start" => config.sample_start = value,
+            "--sample-step" => config.sample_step = value,
+            _ => panic!("Unknown argument {arg}"),
+        }
+    }
+    if config.width == 0 || config.sprite_width == 0 || config.sample_step == 0 {
+        panic!("The display width, the sprite width and the sample step must not be zero");
+    }
+
+    // Next, read the contents of the input file into a string for easier processing.
+    let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
+
+    if annotate_mode {
+        annotate(&input);
+        return;
+    }
+
+    if assemble_mode {
+        // The bitmap determines the size of the display.
+        let (pixels, width) = parse_bitmap(&input);
+        config.width = width.max(1);
+        config.height = pixels.len() / config.width;
+        let program = assemble(&pixels, &config).expect("The bitmap can't be drawn by any program");
+        // Double-check the program by running it on the CRT.
+        let mut crt = Crt::new(config);
+        for (i, x) in trace(&program.join("\n")).iter().enumerate() {
+            crt.process_cycle(i, *x);
+        }
+        if crt.screen != pixels {
+            panic!("The assembled program doesn't reproduce the bitmap");
+        }
+        for instruction in &program {
+            println!("{instruction}");
+        }
+        return;
+    }
+
+    // --- TASK BEGIN ---
+
+    // Both parts are computed from the very same trace of X.
+    let x_over_time = trace(&input);
+
+    // PART ONE
+    println!(
+        "Signal strength: {}",
+        signal_strength(&x_over_time, &config)
+    );
+
+    // PART TWO
+    // Reserve the CRT and ...
+    let mut crt = Crt::new(config);
+
+    // ... iterate over all cycles to compute what would be shown on the screen.
+    for (i, x) in x_over_time.iter().enumerate() {
+        crt.process_cycle(i, *x);
+    }
+
+    // Finally, print said screen.
+    crt.print();
+}
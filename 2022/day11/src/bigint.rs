@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+
+// A minimal arbitrary-precision signed integer, just enough to run the monkeys
+// without any relief at all. The magnitude is stored as little-endian base 2^32
+// limbs without trailing zero limbs, so zero is an empty vector and never negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    mag: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn from_i64(value: i64) -> BigInt {
+        let mut n = value.unsigned_abs();
+        let mut mag = Vec::new();
+        while n > 0 {
+            mag.push(n as u32);
+            n >>= 32;
+        }
+        BigInt {
+            negative: value < 0,
+            mag,
+        }
+    }
+
+    // Build a number from a sign and a magnitude, normalizing the representation.
+    fn from_parts(negative: bool, mut mag: Vec<u32>) -> BigInt {
+        while mag.last() == Some(&0) {
+            mag.pop();
+        }
+        BigInt {
+            negative: negative && !mag.is_empty(),
+            mag,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &other.mag));
+        }
+        // Different signs, so subtract the smaller magnitude from the bigger one.
+        match cmp_mag(&self.mag, &other.mag) {
+            Ordering::Less => BigInt::from_parts(other.negative, sub_mag(&other.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &other.mag)),
+        }
+    }
+
+    pub fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&BigInt::from_parts(!other.negative, other.mag.clone()))
+    }
+
+    pub fn mul(&self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_mag(&self.mag, &other.mag),
+        )
+    }
+
+    // Divide, rounding towards zero just like the primitive integer types do.
+    pub fn div(&self, other: &BigInt) -> BigInt {
+        if other.is_zero() {
+            panic!("Attempt to divide by zero");
+        }
+        let (quotient, _) = divrem_mag(&self.mag, &other.mag);
+        BigInt::from_parts(self.negative != other.negative, quotient)
+    }
+
+    // The remainder of dividing by a small positive modulus. As with `%` on the
+    // primitive integer types, the result takes the sign of `self`.
+    pub fn rem_i64(&self, modulus: i64) -> i64 {
+        assert!(modulus > 0, "Modulus must be positive");
+        let modulus = modulus as u128;
+        let rem = self
+            .mag
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| ((acc << 32) | limb as u128) % modulus)
+            as i64;
+        if self.negative {
+            -rem
+        } else {
+            rem
+        }
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+// Subtract magnitudes, which requires a >= b.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        }
+        result.push(diff as u32);
+    }
+    result
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = result[i + j] as u64 + x as u64 * y as u64 + carry;
+            result[i + j] = cur as u32;
+            carry = cur >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    result
+}
+
+// Long division on magnitudes, returning quotient and remainder.
+// Single-limb divisors take the fast path, everything else goes bit by bit.
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if let [divisor] = b {
+        let divisor = *divisor as u64;
+        let mut quotient = vec![0u32; a.len()];
+        let mut rem = 0u64;
+        for (i, &limb) in a.iter().enumerate().rev() {
+            let cur = (rem << 32) | limb as u64;
+            quotient[i] = (cur / divisor) as u32;
+            rem = cur % divisor;
+        }
+        return (quotient, vec![rem as u32]);
+    }
+
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem: Vec<u32> = Vec::new();
+    for bit in (0..a.len() * 32).rev() {
+        // rem = rem * 2 + next bit of a.
+        rem = add_mag(&rem, &rem);
+        if (a[bit / 32] >> (bit % 32)) & 1 == 1 {
+            rem = add_mag(&rem, &[1]);
+        }
+        while rem.last() == Some(&0) {
+            rem.pop();
+        }
+        if cmp_mag(&rem, b) != Ordering::Less {
+            rem = sub_mag(&rem, b);
+            while rem.last() == Some(&0) {
+                rem.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, rem)
+}
@@ -0,0 +1,671 @@
+mod bigint;
+
+use bigint::BigInt;
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+
+// Represent the operation to perform on the worry level as a small expression tree
+// over the old worry level and integer constants.
+#[derive(Clone, Debug)]
+enum MonkeyOperation {
+    Old,
+    Constant(i64),
+    Add(Box<MonkeyOperation>, Box<MonkeyOperation>),
+    Subtract(Box<MonkeyOperation>, Box<MonkeyOperation>),
+    Multiply(Box<MonkeyOperation>, Box<MonkeyOperation>),
+    Divide(Box<MonkeyOperation>, Box<MonkeyOperation>),
+}
+
+impl MonkeyOperation {
+    // Parse an expression like "old * 19" or "(old + 3) * old - 1".
+    // The usual precedence applies: `*` and `/` bind tighter than `+` and `-`,
+    // all of them are left-associative and parentheses can be used for grouping.
+    fn parse(text: &str) -> Result<MonkeyOperation, String> {
+        let tokens = tokenize(text)?;
+        let mut pos = 0;
+        let op = Self::parse_sum(&tokens, &mut pos)?;
+        match tokens.get(pos) {
+            None => Ok(op),
+            Some(t) => Err(format!("Unexpected {t:?} in operation {text:?}")),
+        }
+    }
+
+    // sum := product (("+" | "-") product)*
+    fn parse_sum(tokens: &[Token], pos: &mut usize) -> Result<MonkeyOperation, String> {
+        let mut lhs = Self::parse_product(tokens, pos)?;
+        while let Some(&Token::Symbol(c @ ('+' | '-'))) = tokens.get(*pos) {
+            *pos += 1;
+            let rhs = Box::new(Self::parse_product(tokens, pos)?);
+            lhs = match c {
+                '+' => MonkeyOperation::Add(Box::new(lhs), rhs),
+                _ => MonkeyOperation::Subtract(Box::new(lhs), rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    // product := atom (("*" | "/") atom)*
+    fn parse_product(tokens: &[Token], pos: &mut usize) -> Result<MonkeyOperation, String> {
+        let mut lhs = Self::parse_atom(tokens, pos)?;
+        while let Some(&Token::Symbol(c @ ('*' | '/'))) = tokens.get(*pos) {
+            *pos += 1;
+            let rhs = Box::new(Self::parse_atom(tokens, pos)?);
+            lhs = match c {
+                '*' => MonkeyOperation::Multiply(Box::new(lhs), rhs),
+                _ => MonkeyOperation::Divide(Box::new(lhs), rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    // atom := "old" | integer | "(" sum ")"
+    fn parse_atom(tokens: &[Token], pos: &mut usize) -> Result<MonkeyOperation, String> {
+        let token = tokens.get(*pos).ok_or("Operation ends unexpectedly")?;
+        *pos += 1;
+        match token {
+            Token::Old => Ok(MonkeyOperation::Old),
+            Token::Number(n) => Ok(MonkeyOperation::Constant(*n)),
+            Token::Symbol('(') => {
+                let inner = Self::parse_sum(tokens, pos)?;
+                match tokens.get(*pos) {
+                    Some(Token::Symbol(')')) => {
+                        *pos += 1;
+                        Ok(inner)
+                    }
+                    _ => Err(String::from("Missing closing parenthesis in operation")),
+                }
+            }
+            Token::Symbol(c) => Err(format!("Unexpected {c:?} in operation")),
+        }
+    }
+
+    // Evaluate the expression for the given old worry level.
+    // Returns None if any step overflows or divides by zero.
+    fn eval<W: Worry>(&self, old: &W) -> Option<W> {
+        match self {
+            MonkeyOperation::Old => Some(old.clone()),
+            MonkeyOperation::Constant(n) => Some(W::from_i64(*n)),
+            MonkeyOperation::Add(a, b) => a.eval(old)?.plus(&b.eval(old)?),
+            MonkeyOperation::Subtract(a, b) => a.eval(old)?.minus(&b.eval(old)?),
+            MonkeyOperation::Multiply(a, b) => a.eval(old)?.times(&b.eval(old)?),
+            MonkeyOperation::Divide(a, b) => a.eval(old)?.divided_by(&b.eval(old)?),
+        }
+    }
+
+    // Whether the expression divides anywhere.
+    fn contains_division(&self) -> bool {
+        match self {
+            MonkeyOperation::Old | MonkeyOperation::Constant(_) => false,
+            MonkeyOperation::Divide(..) => true,
+            MonkeyOperation::Add(a, b)
+            | MonkeyOperation::Subtract(a, b)
+            | MonkeyOperation::Multiply(a, b) => a.contains_division() || b.contains_division(),
+        }
+    }
+}
+
+// The building blocks of a MonkeyOperation's textual representation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Token {
+    Old,
+    Number(i64),
+    Symbol(char),
+}
+
+// Split an operation into its tokens. Whitespace is optional.
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                number.push(d);
+                chars.next();
+            }
+            let n = number
+                .parse::<i64>()
+                .map_err(|e| format!("Invalid number {number} in operation: {e}"))?;
+            tokens.push(Token::Number(n));
+        } else if c.is_alphabetic() {
+            let mut word = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_alphanumeric()) {
+                word.push(d);
+                chars.next();
+            }
+            if word != "old" {
+                return Err(format!("Unknown variable {word:?} in operation"));
+            }
+            tokens.push(Token::Old);
+        } else if "+-*/()".contains(c) {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        } else {
+            return Err(format!("Unexpected character {c:?} in operation"));
+        }
+    }
+    Ok(tokens)
+}
+
+// A worry level the monkeys can do arithmetic on. With some relief applied after
+// every inspection an i128 is enough, without any we need a BigInt. The operations
+// return None on overflow or division by zero.
+trait Worry: Clone + std::fmt::Debug {
+    fn from_i64(value: i64) -> Self;
+    fn plus(&self, other: &Self) -> Option<Self>;
+    fn minus(&self, other: &Self) -> Option<Self>;
+    fn times(&self, other: &Self) -> Option<Self>;
+    fn divided_by(&self, other: &Self) -> Option<Self>;
+    fn rem_i64(&self, modulus: i64) -> i64;
+}
+
+// Relieved worry levels fit in an i64, but the operation is evaluated in an i128 so
+// that squaring them can't overflow.
+impl Worry for i128 {
+    fn from_i64(value: i64) -> Self {
+        value as i128
+    }
+    fn plus(&self, other: &Self) -> Option<Self> {
+        self.checked_add(*other)
+    }
+    fn minus(&self, other: &Self) -> Option<Self> {
+        self.checked_sub(*other)
+    }
+    fn times(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(*other)
+    }
+    fn divided_by(&self, other: &Self) -> Option<Self> {
+        self.checked_div(*other)
+    }
+    fn rem_i64(&self, modulus: i64) -> i64 {
+        (self % modulus as i128) as i64
+    }
+}
+
+impl Worry for BigInt {
+    fn from_i64(value: i64) -> Self {
+        BigInt::from_i64(value)
+    }
+    fn plus(&self, other: &Self) -> Option<Self> {
+        Some(self.add(other))
+    }
+    fn minus(&self, other: &Self) -> Option<Self> {
+        Some(self.sub(other))
+    }
+    fn times(&self, other: &Self) -> Option<Self> {
+        Some(self.mul(other))
+    }
+    fn divided_by(&self, other: &Self) -> Option<Self> {
+        (!other.is_zero()).then(|| self.div(other))
+    }
+    fn rem_i64(&self, modulus: i64) -> i64 {
+        BigInt::rem_i64(self, modulus)
+    }
+}
+
+// How the worry level is kept in check after a monkey inspected an item.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Relief {
+    // Divide the worry level by a constant, rounding down (part one uses 3).
+    Divide(i64),
+    // Reduce the worry level modulo the least common multiple of all test divisors.
+    // This doesn't change the outcome of any test, but keeps the numbers small (part two).
+    ModuloLcm,
+    // Leave the worry level alone. Numbers grow quickly, so this uses big integers.
+    None,
+}
+
+impl Relief {
+    // Parse "div:<k>", "lcm" or "none".
+    fn parse(text: &str) -> Result<Relief, String> {
+        match text {
+            "lcm" => Ok(Relief::ModuloLcm),
+            "none" => Ok(Relief::None),
+            _ => match text.strip_prefix("div:").map(|k| k.parse::<i64>()) {
+                Some(Ok(k)) if k > 0 => Ok(Relief::Divide(k)),
+                _ => Err(format!(
+                    "Invalid relief {text:?}, expected \"div:<k>\" with k > 0, \"lcm\" or \"none\""
+                )),
+            },
+        }
+    }
+}
+
+// Represent all the data for an individual monkey.
+#[derive(Debug)]
+struct Monkey {
+    items: VecDeque<i64>,
+    op: MonkeyOperation,
+    test_divisor: i64,
+    true_dest: usize,
+    false_dest: usize,
+}
+
+// A single throw of an item, as recorded by the trace.
+#[derive(Copy, Clone, Debug)]
+struct Throw {
+    item: usize,
+    round: u64,
+    from: usize,
+    to: usize,
+}
+
+// The outcome of simulating the monkeys for a number of rounds.
+struct Simulation {
+    // Total number of inspections per monkey.
+    inspect_counts: Vec<u64>,
+    // Cumulative number of inspections per monkey after every round, if requested.
+    round_counts: Option<Vec<Vec<u64>>>,
+    // Every throw in order, if tracing was requested. Items are numbered in the
+    // order they appear in the input.
+    trace: Option<Vec<Throw>>,
+}
+
+// The product of the two highest inspect counts. With extrapolated round counts
+// this easily exceeds u64.
+fn monkey_business(inspect_counts: &[u64]) -> u128 {
+    let mut counts = inspect_counts.to_vec();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts.iter().take(2).map(|&c| c as u128).product()
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The least common multiple of all test divisors. Unlike their product, this stays
+// small when divisors repeat or share factors.
+fn divisor_lcm(monkeys: &[Monkey]) -> Result<i64, String> {
+    monkeys.iter().try_fold(1i64, |acc, m| {
+        (acc / gcd(acc, m.test_divisor))
+            .checked_mul(m.test_divisor)
+            .ok_or_else(|| String::from("The LCM of all test divisors does not fit in an i64"))
+    })
+}
+
+// Reducing modulo the LCM keeps every test result intact only as long as the operations
+// stick to `+`, `-` and `*`. Integer division doesn't commute with `mod`, so refuse
+// instead of silently getting the counts wrong.
+fn check_modulo_relief(monkeys: &[Monkey]) -> Result<(), String> {
+    match monkeys.iter().position(|m| m.op.contains_division()) {
+        Some(m) => Err(format!(
+            "The LCM relief can't be used since monkey {m}'s operation divides, \
+             try --relief none or div:<k> instead"
+        )),
+        None => Ok(()),
+    }
+}
+
+// Describe a failed inspection for the error message.
+fn overflow_error(monkey: usize, item: usize, round: u64, worry: &impl std::fmt::Debug) -> String {
+    format!(
+        "Monkey {monkey} overflowed inspecting item {item} (worry level {worry:?}) in round {round}"
+    )
+}
+
+// Simulate the given number of rounds, tracking worry levels as W.
+fn simulate<W: Worry>(
+    monkeys: &[Monkey],
+    rounds: u64,
+    relief: Relief,
+    trace: bool,
+    record_rounds: bool,
+) -> Result<Simulation, String> {
+    // Only the modulo relief needs the LCM, so don't fail on it otherwise.
+    let lcm = match relief {
+        Relief::ModuloLcm => {
+            check_modulo_relief(monkeys)?;
+            divisor_lcm(monkeys)?
+        }
+        _ => 1,
+    };
+
+    // Give every item a number so it can be followed through the trace.
+    let mut next_item = 0;
+    let mut items: Vec<VecDeque<(usize, W)>> = monkeys
+        .iter()
+        .map(|m| {
+            m.items
+                .iter()
+                .map(|&worry| {
+                    next_item += 1;
+                    (next_item - 1, W::from_i64(worry))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut inspect_counts = vec![0u64; monkeys.len()];
+    let mut round_counts = record_rounds.then(Vec::new);
+    let mut throws = trace.then(Vec::new);
+
+    for round in 1..=rounds {
+        // Go through all monkeys one-by-one.
+        for (m, monkey) in monkeys.iter().enumerate() {
+            // Go through the queue of items, starting with the front.
+            while let Some((item, worry)) = items[m].pop_front() {
+                // First, apply the monkey's operation.
+                let worry = monkey
+                    .op
+                    .eval(&worry)
+                    .ok_or_else(|| overflow_error(m, item, round, &worry))?;
+                // Monkey inspected an item, so increase the inspect count.
+                inspect_counts[m] += 1;
+                // Then get some relief.
+                let worry = match relief {
+                    Relief::Divide(k) => worry.divided_by(&W::from_i64(k)).unwrap(),
+                    Relief::ModuloLcm => W::from_i64(worry.rem_i64(lcm)),
+                    Relief::None => worry,
+                };
+                // Perform the test to determine the destination monkey.
+                let dest = if worry.rem_i64(monkey.test_divisor) == 0 {
+                    monkey.true_dest
+                } else {
+                    monkey.false_dest
+                };
+                if let Some(throws) = throws.as_mut() {
+                    throws.push(Throw {
+                        item,
+                        round,
+                        from: m,
+                        to: dest,
+                    });
+                }
+                // And send the item to that destination.
+                items[dest].push_back((item, worry));
+            }
+        }
+        if let Some(round_counts) = round_counts.as_mut() {
+            round_counts.push(inspect_counts.clone());
+        }
+    }
+
+    Ok(Simulation {
+        inspect_counts,
+        round_counts,
+        trace: throws,
+    })
+}
+
+// Pick the worry level representation that fits the relief strategy.
+fn run(
+    monkeys: &[Monkey],
+    rounds: u64,
+    relief: Relief,
+    trace: bool,
+    record_rounds: bool,
+) -> Result<Simulation, String> {
+    match relief {
+        Relief::None => simulate::<BigInt>(monkeys, rounds, relief, trace, record_rounds),
+        _ => simulate::<i128>(monkeys, rounds, relief, trace, record_rounds),
+    }
+}
+
+// Count the inspections per monkey over the given number of rounds with the modulo LCM
+// relief, without simulating every round.
+//
+// An item never affects any other item, so each one can be followed on its own. Its
+// state before an inspection is the monkey holding it and its worry level modulo the
+// LCM. The holder also fixes the phase within the round: throwing to a monkey with a
+// higher index means it is inspected again in the same round, otherwise it waits for
+// the next one (monkeys never throw to themselves). There are only finitely many states, so eventually one repeats and
+// from there on the item goes through the same inspections every few rounds.
+fn count_inspections_cyclic(monkeys: &[Monkey], rounds: u64) -> Result<Vec<u64>, String> {
+    check_modulo_relief(monkeys)?;
+    let lcm = divisor_lcm(monkeys)? as i128;
+    let mut inspect_counts = vec![0u64; monkeys.len()];
+
+    // Items are numbered in input order, as in the trace.
+    let items = monkeys
+        .iter()
+        .enumerate()
+        .flat_map(|(start, m)| m.items.iter().map(move |&worry| (start, worry)));
+    for (item, (start, worry)) in items.enumerate() {
+        // All inspections of this item so far as (round, monkey), and the index
+        // into that list at which each state was first seen.
+        let mut history: Vec<(u64, usize)> = Vec::new();
+        let mut seen: HashMap<(usize, i128), usize> = HashMap::new();
+        let (mut holder, mut worry, mut round) = (start, worry as i128 % lcm, 1);
+
+        while round <= rounds {
+            if let Some(&first) = seen.get(&(holder, worry)) {
+                // From `first` on, the inspections repeat every `period` rounds.
+                let period = round - history[first].0;
+                for &(r, m) in &history[first..] {
+                    inspect_counts[m] += (rounds - r) / period + 1;
+                }
+                history.truncate(first);
+                break;
+            }
+            seen.insert((holder, worry), history.len());
+            history.push((round, holder));
+
+            let m = &monkeys[holder];
+            worry =
+                m.op.eval(&worry)
+                    .ok_or_else(|| overflow_error(holder, item, round, &worry))?
+                    % lcm;
+            let dest = if worry % m.test_divisor as i128 == 0 {
+                m.true_dest
+            } else {
+                m.false_dest
+            };
+            if dest < holder {
+                round += 1;
+            }
+            holder = dest;
+        }
+
+        // Everything before the cycle (or everything, if the rounds ran out first).
+        for &(_, m) in &history {
+            inspect_counts[m] += 1;
+        }
+    }
+    Ok(inspect_counts)
+}
+
+// Write the cumulative inspect counts as CSV, one line per round, for plotting.
+fn write_counts(path: &str, round_counts: &[Vec<u64>]) -> std::io::Result<()> {
+    let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+    write!(out, "round")?;
+    for m in 0..round_counts.first().map_or(0, |c| c.len()) {
+        write!(out, ",monkey {m}")?;
+    }
+    writeln!(out)?;
+    for (round, counts) in round_counts.iter().enumerate() {
+        write!(out, "{}", round + 1)?;
+        for count in counts {
+            write!(out, ",{count}")?;
+        }
+        writeln!(out)?;
+    }
+    out.flush()
+}
+
+// Write every throw as CSV, so each item's path can be followed by filtering on it.
+fn write_trace(path: &str, throws: &[Throw]) -> std::io::Result<()> {
+    let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+    writeln!(out, "item,round,from,to")?;
+    for t in throws {
+        writeln!(out, "{},{},{},{}", t.item, t.round, t.from, t.to)?;
+    }
+    out.flush()
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [--rounds <n>] [--relief div:<k>|lcm|none] \
+             [--trace <csv-file>] [--counts <csv-file>] [--simulate]\nNo input file provided. Exiting."
+        );
+    }
+
+    // Without --rounds and --relief, both parts are solved. Otherwise a single custom
+    // simulation is run. The trace and counts describe the custom run or part two.
+    // --simulate runs every round even where the cycle detection could skip ahead.
+    let mut rounds = None;
+    let mut relief = None;
+    let mut trace_path = None;
+    let mut counts_path = None;
+    let mut force_simulation = false;
+    let mut rest = args.iter().skip(2);
+    while let Some(arg) = rest.next() {
+        let mut value = || {
+            rest.next()
+                .unwrap_or_else(|| panic!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--rounds" => {
+                let v = value();
+                rounds = Some(
+                    v.parse::<u64>()
+                        .unwrap_or_else(|_| panic!("Invalid round count {v:?}")),
+                );
+            }
+            "--relief" => relief = Some(Relief::parse(value()).unwrap_or_else(|e| panic!("{e}"))),
+            "--trace" => trace_path = Some(value().clone()),
+            "--counts" => counts_path = Some(value().clone()),
+            "--simulate" => force_simulation = true,
+            _ => panic!("Unknown argument {arg:?}"),
+        }
+    }
+
+    // Next, read the contents of the input file into a string for easier processing.
+    let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
+    // Line-by-line processing is easiest.
+    let mut input = input.lines();
+
+    // --- TASK BEGIN ---
+
+    // Parse and collect all of the data, first and foremost.
+    let mut monkeys: Vec<Monkey> = Vec::new();
+
+    // Iterate line-by-line.
+    loop {
+        // We don't care about the "Monkey 0" line.
+        if input.next().is_none() {
+            // End of the file? Then we're done.
+            break;
+        }
+        // Parse the starting items.
+        let (_, items) = input.next().unwrap().split_at(18);
+        // Parse the actual numbers and collect them into an integer vector.
+        let items: VecDeque<_> = items
+            .split(", ")
+            .map(|x| x.parse::<i64>().unwrap())
+            .collect();
+
+        // Parse the operation, i.e. everything to the right of "new =".
+        let line = input.next().unwrap();
+        let (_, expression) = line
+            .split_once('=')
+            .unwrap_or_else(|| panic!("Could not parse operation {line:?}"));
+        let op = MonkeyOperation::parse(expression)
+            .unwrap_or_else(|e| panic!("Could not parse operation {line:?}: {e}"));
+
+        // Parse the number by which to divide.
+        let test_divisor = input.next().unwrap().split_at(21).1.parse::<i64>().unwrap();
+        if test_divisor <= 0 {
+            panic!("Test divisor must be positive, got {test_divisor}");
+        }
+
+        // Parse the monkey destinations in the true and false case.
+        let true_dest = input
+            .next()
+            .unwrap()
+            .split_at(29)
+            .1
+            .parse::<usize>()
+            .unwrap();
+        let false_dest = input
+            .next()
+            .unwrap()
+            .split_at(30)
+            .1
+            .parse::<usize>()
+            .unwrap();
+
+        // Skip the whitespace line.
+        input.next();
+
+        // Finally, actually construct the monkey out of all this and add it to the list.
+        monkeys.push(Monkey {
+            items,
+            op,
+            test_divisor,
+            true_dest,
+            false_dest,
+        })
+    }
+
+    // Every throw has to go to another monkey that actually exists. Throwing to itself
+    // would put the item back onto the very queue being emptied, so it could be
+    // inspected over and over within one round.
+    for (m, monkey) in monkeys.iter().enumerate() {
+        for dest in [monkey.true_dest, monkey.false_dest] {
+            if dest == m {
+                panic!("Monkey {m} throws items to itself");
+            }
+            if dest >= monkeys.len() {
+                panic!(
+                    "Monkey {m} throws items to monkey {dest}, but there are only {} monkeys",
+                    monkeys.len()
+                );
+            }
+        }
+    }
+
+    // The per-round counts and the trace need the full simulation. Otherwise the modulo
+    // LCM relief lets us skip ahead using the cycle of every item.
+    let simulate_all = trace_path.is_some() || counts_path.is_some() || force_simulation;
+    let mut simulation = None;
+    let mut inspect_counts = |rounds: u64, relief: Relief| {
+        if relief == Relief::ModuloLcm && !simulate_all {
+            count_inspections_cyclic(&monkeys, rounds).unwrap_or_else(|e| panic!("{e}"))
+        } else {
+            let result = run(
+                &monkeys,
+                rounds,
+                relief,
+                trace_path.is_some(),
+                counts_path.is_some(),
+            )
+            .unwrap_or_else(|e| panic!("{e}"));
+            let counts = result.inspect_counts.clone();
+            simulation = Some(result);
+            counts
+        }
+    };
+
+    if rounds.is_none() && relief.is_none() {
+        let part1 = inspect_counts(20, Relief::Divide(3));
+        println!("Part 1 monkey business: {}", monkey_business(&part1));
+        let part2 = inspect_counts(10000, Relief::ModuloLcm);
+        println!("Part 2 monkey business: {}", monkey_business(&part2));
+    } else {
+        let rounds = rounds.unwrap_or(20);
+        let relief = relief.unwrap_or(Relief::Divide(3));
+        let counts = inspect_counts(rounds, relief);
+        println!("Inspect counts: {counts:?}");
+        println!("Monkey business: {}", monkey_business(&counts));
+    }
+
+    if let Some(simulation) = simulation {
+        if let (Some(path), Some(round_counts)) = (counts_path, &simulation.round_counts) {
+            write_counts(&path, round_counts)
+                .unwrap_or_else(|e| panic!("Could not write counts to {path}: {e}"));
+        }
+        if let (Some(path), Some(throws)) = (trace_path, &simulation.trace) {
+            write_trace(&path, throws)
+                .unwrap_or_else(|e| panic!("Could not write trace to {path}: {e}"));
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+// Coordinates of a cell on the map as (x, y).
+// Origin of the coordinate system is the top left.
+type Pos = (usize, usize);
+
+// The elevation map, stored row by row, together with the marked start and end cells.
+struct Heightmap {
+    width: usize,
+    height: usize,
+    elevation: Vec<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+// The outcome of a shortest-path search over the heightmap.
+struct Search {
+    // Number of steps from the closest start cell, for every cell reached so far.
+    dist: Vec<Option<usize>>,
+    // The cell we came from, for every reached cell that isn't a start cell.
+    previous: Vec<Option<Pos>>,
+    // The first goal cell reached, if any.
+    goal: Option<Pos>,
+    width: usize,
+}
+
+// Whether a search reached its goal.
+enum Outcome {
+    // The shortest route, from its start cell to the goal.
+    Found(Vec<Pos>),
+    // No goal can be reached. Since the search ran out of cells to visit, it
+    // covered every cell reachable from the start cells, and these are listed here.
+    NoPath { reachable: Vec<Pos> },
+}
+
+impl Search {
+    // Retrace the route from the start to the goal that was found, or list everything
+    // that could be reached instead.
+    fn outcome(&self) -> Outcome {
+        let Some(mut current) = self.goal else {
+            let reachable = (0..self.dist.len())
+                .filter(|&i| self.dist[i].is_some())
+                .map(|i| (i % self.width, i / self.width))
+                .collect();
+            return Outcome::NoPath { reachable };
+        };
+        let mut path = vec![current];
+        while let Some(prev) = self.previous[current.1 * self.width + current.0] {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        Outcome::Found(path)
+    }
+}
+
+// The regular climbing rule: at most one step up, but arbitrarily far down.
+fn climb_up(from: u8, to: u8) -> bool {
+    to <= from + 1
+}
+
+// The same rule walked backwards, i.e. for searching from the summit downwards.
+fn climb_down(from: u8, to: u8) -> bool {
+    from <= to + 1
+}
+
+impl Heightmap {
+    // Parse the map, which must be rectangular, consist only of the letters a to z and
+    // contain exactly one start marker S and one destination marker E.
+    fn parse(input: &str) -> Result<Heightmap, String> {
+        // Understand the size of the map we're working with.
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        let height = input.lines().count();
+        if width == 0 {
+            return Err(String::from("The map is empty"));
+        }
+
+        let mut elevation = Vec::with_capacity(width * height);
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(format!(
+                    "Line {} is {} cells wide, expected {width}",
+                    y + 1,
+                    line.chars().count()
+                ));
+            }
+            for (x, c) in line.chars().enumerate() {
+                // Translate the character into the correct elevation and remember
+                // where the start and destination are.
+                elevation.push(match c {
+                    'S' => {
+                        starts.push((x, y));
+                        0
+                    }
+                    'E' => {
+                        ends.push((x, y));
+                        25
+                    }
+                    'a'..='z' => (c as u8) - b'a',
+                    _ => return Err(format!("Unexpected character {c:?} at ({x}, {y})")),
+                });
+            }
+        }
+
+        // Exactly one of each marker, anything else is ambiguous.
+        let single = |cells: Vec<Pos>, marker: &str| match cells[..] {
+            [pos] => Ok(pos),
+            [] => Err(format!("No {marker} on the map")),
+            _ => Err(format!(
+                "Expected exactly one {marker}, found {} at {}",
+                cells.len(),
+                cells
+                    .iter()
+                    .map(|(x, y)| format!("({x}, {y})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        };
+
+        Ok(Heightmap {
+            width,
+            height,
+            elevation,
+            start: single(starts, "start marker 'S'")?,
+            end: single(ends, "destination marker 'E'")?,
+        })
+    }
+
+    fn elevation(&self, (x, y): Pos) -> u8 {
+        self.elevation[y * self.width + x]
+    }
+
+    // All cells adjacent to the given one, in the order north, south, west, east.
+    fn neighbors(&self, (x, y): Pos) -> impl Iterator<Item = Pos> {
+        let north = (y > 0).then(|| (x, y - 1));
+        let south = (y + 1 < self.height).then_some((x, y + 1));
+        let west = (x > 0).then(|| (x - 1, y));
+        let east = (x + 1 < self.width).then_some((x + 1, y));
+        [north, south, west, east].into_iter().flatten()
+    }
+
+    // All cells with the given elevation.
+    fn cells_at(&self, elevation: u8) -> Vec<Pos> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&pos| self.elevation(pos) == elevation)
+            .collect()
+    }
+
+    // Shade an elevation from dark green (a) over brown to white (z).
+    fn elevation_color(elevation: u8) -> [u8; 3] {
+        const STOPS: [[f64; 3]; 3] = [[30.0, 90.0, 30.0], [150.0, 110.0, 60.0], [245.0; 3]];
+        let t = elevation as f64 / 25.0 * 2.0;
+        let (from, to) = if t < 1.0 {
+            (STOPS[0], STOPS[1])
+        } else {
+            (STOPS[1], STOPS[2])
+        };
+        let t = if t < 1.0 { t } else { t - 1.0 };
+        [0, 1, 2].map(|i| (from[i] + (to[i] - from[i]) * t) as u8)
+    }
+
+    // Export the map as a binary PPM image with one square of pixels per cell.
+    // Elevation is shaded from dark green (a) to white (z). The route is drawn as a line
+    // through the cell centers, going from yellow at its start to magenta at its end, and
+    // S and E are marked with a green and a red dot.
+    // With a distance field, every reached cell is tinted blue from bright (close to the
+    // start) to dark (far away), and cells that were never reached are hatched red.
+    fn export_ppm(&self, path: &str, route: &[Pos], field: Option<&Search>) -> std::io::Result<()> {
+        // Blow up the map so the route and markers can actually be seen.
+        let scale = (512 / self.width.max(self.height)).max(4);
+        let (w, h) = (self.width * scale, self.height * scale);
+        let mut pixels = vec![[0u8; 3]; w * h];
+
+        // The terrain, optionally tinted by the distance field.
+        let max_dist = field
+            .and_then(|f| f.dist.iter().flatten().max().copied())
+            .unwrap_or(0)
+            .max(1);
+        for (py, row) in pixels.chunks_mut(w).enumerate() {
+            for (px, pixel) in row.iter_mut().enumerate() {
+                let pos = (px / scale, py / scale);
+                let base = Self::elevation_color(self.elevation(pos));
+                *pixel = match field.map(|f| f.dist[pos.1 * self.width + pos.0]) {
+                    None => base,
+                    Some(Some(dist)) => {
+                        let closeness = 1.0 - dist as f64 / max_dist as f64;
+                        let tint = [0.0, 80.0 + 100.0 * closeness, 120.0 + 135.0 * closeness];
+                        [0, 1, 2].map(|i| (base[i] as f64 * 0.4 + tint[i] * 0.6) as u8)
+                    }
+                    Some(None) if (px + py) % 4 == 0 => [200, 0, 0],
+                    Some(None) => base.map(|c| c / 2),
+                };
+            }
+        }
+
+        // The route, as a thick line between the centers of consecutive cells.
+        let center = |(x, y): Pos| (x * scale + scale / 2, y * scale + scale / 2);
+        let thickness = (scale / 4).max(1);
+        for (i, pair) in route.windows(2).enumerate() {
+            let t = i as f64 / (route.len() - 1).max(1) as f64;
+            let color = [255, (255.0 * (1.0 - t)) as u8, (255.0 * t) as u8];
+            let ((x0, y0), (x1, y1)) = (center(pair[0]), center(pair[1]));
+            for py in y0.min(y1) - thickness / 2..=y0.max(y1) + thickness / 2 {
+                for px in x0.min(x1) - thickness / 2..=x0.max(x1) + thickness / 2 {
+                    pixels[py * w + px] = color;
+                }
+            }
+        }
+
+        // The markers for S and E on top of everything else.
+        for (marker, color) in [(self.start, [0, 200, 0]), (self.end, [220, 0, 0])] {
+            let (cx, cy) = center(marker);
+            let radius = (scale / 3).max(1);
+            for py in cy - radius..=cy + radius {
+                for px in cx - radius..=cx + radius {
+                    if px.abs_diff(cx).pow(2) + py.abs_diff(cy).pow(2) <= radius.pow(2) {
+                        pixels[py * w + px] = color;
+                    }
+                }
+            }
+        }
+
+        let mut out = BufWriter::new(File::create(path)?);
+        write!(out, "P6\n{w} {h}\n255\n")?;
+        for pixel in pixels {
+            out.write_all(&pixel)?;
+        }
+        out.flush()
+    }
+
+    // Find the shortest route from any of the start cells to a cell satisfying the goal
+    // predicate, moving only between neighbors allowed by the climb rule. Since every
+    // step costs the same, a breadth-first search is all it takes.
+    fn search(
+        &self,
+        starts: &[Pos],
+        can_climb: impl Fn(u8, u8) -> bool,
+        is_goal: impl Fn(Pos) -> bool,
+    ) -> Search {
+        let mut search = Search {
+            dist: vec![None; self.elevation.len()],
+            previous: vec![None; self.elevation.len()],
+            goal: None,
+            width: self.width,
+        };
+
+        // Keep track of all cells that need to be visited still.
+        let mut to_visit = VecDeque::new();
+        for &(x, y) in starts {
+            if search.dist[y * self.width + x].is_none() {
+                search.dist[y * self.width + x] = Some(0);
+                to_visit.push_back((x, y));
+            }
+        }
+
+        while let Some(current) = to_visit.pop_front() {
+            // Have we reached a goal? Then we're done here.
+            if is_goal(current) {
+                search.goal = Some(current);
+                break;
+            }
+
+            let dist = search.dist[current.1 * self.width + current.0].unwrap();
+            for nb in self.neighbors(current) {
+                let index = nb.1 * self.width + nb.0;
+                if search.dist[index].is_none()
+                    && can_climb(self.elevation(current), self.elevation(nb))
+                {
+                    search.dist[index] = Some(dist + 1);
+                    search.previous[index] = Some(current);
+                    to_visit.push_back(nb);
+                }
+            }
+        }
+
+        search
+    }
+}
+
+fn main() {
+    // Use command line arguments to specify the input filename.
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        panic!(
+            "Usage: ./main <input-file> [--reverse] [--export <prefix> [--distances]]\n\
+             No input file provided. Exiting."
+        );
+    }
+
+    // Part two searches from every 'a' at once. With --reverse it walks down from E
+    // to the closest 'a' instead, which gives the same answer.
+    let reverse = args.iter().skip(2).any(|a| a == "--reverse");
+    // Export `<prefix>-part1.ppm` and `<prefix>-part2.ppm` showing the routes, optionally
+    // with the distance field of each search.
+    let export_prefix = args
+        .iter()
+        .position(|a| a == "--export")
+        .map(|i| args.get(i + 1).expect("Missing prefix for --export"));
+    let with_distances = args.iter().skip(2).any(|a| a == "--distances");
+
+    // Next, read the contents of the input file into a string for easier processing.
+    let input = std::fs::read_to_string(&args[1]).expect("Error opening file");
+
+    // --- TASK BEGIN ---
+
+    let map = Heightmap::parse(&input).unwrap_or_else(|e| panic!("Invalid map: {e}"));
+
+    // Write the image for one part. The distance field comes from running the same
+    // search without a goal, so it covers everything reachable and not just the cells
+    // visited before the goal turned up.
+    let export = |part: u32, route: &[Pos], full_search: &dyn Fn() -> Search| {
+        if let Some(prefix) = export_prefix {
+            let path = format!("{prefix}-part{part}.ppm");
+            let field = with_distances.then(full_search);
+            map.export_ppm(&path, route, field.as_ref())
+                .unwrap_or_else(|e| panic!("Error writing {path}: {e}"));
+            println!("Exported image to {path}");
+        }
+    };
+
+    // PART ONE: from S to E.
+    let search = map.search(&[map.start], climb_up, |pos| pos == map.end);
+    let path = report(&map, "from S to E", &search);
+    export(1, &path, &|| map.search(&[map.start], climb_up, |_| false));
+
+    // PART TWO: from any 'a' to E.
+    let (starts, can_climb): (Vec<Pos>, fn(u8, u8) -> bool) = if reverse {
+        (vec![map.end], climb_down)
+    } else {
+        (map.cells_at(0), climb_up)
+    };
+    let is_goal = |pos: Pos| {
+        if reverse {
+            map.elevation(pos) == 0
+        } else {
+            pos == map.end
+        }
+    };
+    let search = map.search(&starts, can_climb, is_goal);
+    let path = report(&map, "from any 'a' to E", &search);
+    export(2, &path, &|| map.search(&starts, can_climb, |_| false));
+}
+
+// Print the outcome of a search: either the route and its length, or the region that
+// could be reached. Returns the route, which is empty if there is none.
+fn report(map: &Heightmap, description: &str, search: &Search) -> Vec<Pos> {
+    match search.outcome() {
+        Outcome::Found(path) => {
+            print_solution(map, &path, '#');
+            println!("Fewest steps {description}: {}", path.len() - 1);
+            path
+        }
+        Outcome::NoPath { reachable } => {
+            print_solution(map, &reachable, '+');
+            println!(
+                "No route {description}, only {} cells (marked +) can be reached",
+                reachable.len()
+            );
+            Vec::new()
+        }
+    }
+}
+
+fn print_solution(map: &Heightmap, cells: &[Pos], mark: char) {
+    // Recreate the input.
+    let mut output: Vec<Vec<char>> = (0..map.height)
+        .map(|y| {
+            (0..map.width)
+                .map(|x| (b'a' + map.elevation((x, y))) as char)
+                .collect()
+        })
+        .collect();
+
+    // Overwrite the highlighted cells with the mark.
+    for &(x, y) in cells {
+        output[y][x] = mark;
+    }
+
+    // Actually print.
+    for line in output {
+        println!("{}", line.into_iter().collect::<String>());
+    }
+}
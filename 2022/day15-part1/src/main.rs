@@ -1,60 +1,33 @@
-use std::collections::{HashSet, VecDeque};
+use aoc_intervals::{Interval, IntervalSet};
+use std::collections::HashSet;
 
 #[derive(Debug, Copy, Clone)]
 struct Sensor {
-    sx: isize,
-    sy: isize,
-    bx: isize,
-    by: isize,
+    sx: i64,
+    sy: i64,
+    bx: i64,
+    by: i64,
 }
 
 impl Sensor {
     // Determine the distance between sensor and beacon, using Manhattan metric.
-    fn beacon_distance(&self) -> isize {
+    fn beacon_distance(&self) -> i64 {
         (self.sx - self.bx).abs() + (self.sy - self.by).abs()
     }
 
-    // fn beacon_impossible(&self, x: isize, y: isize) -> bool {
+    // fn beacon_impossible(&self, x: i64, y: i64) -> bool {
     //     let d = (self.sx - x).abs() + (self.sy - y).abs();
     //     d <= self.beacon_distance()
     // }
 
-    // Return a range for a given y-coordinate where no beacon could
+    // Return the interval for a given y-coordinate where no beacon could
     // possibly be present for this sensor.
-    fn covered_in_line(&self, y: isize) -> Option<(isize, isize)> {
+    fn covered_in_line(&self, y: i64) -> Option<Interval> {
         let d = self.beacon_distance() - (self.sy - y).abs();
         if d < 0 {
             None
         } else {
-            Some(((self.sx - d), (self.sx + d)))
-        }
-    }
-}
-
-// Collapse multiple ranges into the minimal set of ranges.
-fn collapse_ranges(ranges: &mut VecDeque<(isize, isize)>) {
-    // First, sort by minimal value..
-    ranges
-        .make_contiguous()
-        .sort_unstable_by(|a, b| a.0.cmp(&b.0));
-
-    // Then continually merge pairs throughout the VecDeque.
-    let mut idx: usize = 0;
-    while idx + 1 < ranges.len() {
-        // Grab the current pair.
-        let a = ranges[idx];
-        let b = ranges[idx + 1];
-        // Check if the minimal vaue of b is contained in a.
-        // In that case we can merge the two.
-        if a.0 <= b.0 && b.0 <= a.1 {
-            // Remove the first of the pair.
-            ranges.remove(idx);
-            // And update the second element.
-            // (which just moved from idx+1 to idx)
-            ranges[idx] = (a.0, a.1.max(b.1));
-        } else {
-            // If no merge took place, move on to the next pair.
-            idx += 1;
+            Some(Interval::new(self.sx - d, self.sx + d))
         }
     }
 }
@@ -82,7 +55,7 @@ fn main() {
                     .take_while(|c| *c != ',' && *c != ':')
                     .collect::<String>()
             })
-            .map(|e| e.parse::<isize>().unwrap())
+            .map(|e| e.parse::<i64>().unwrap())
             .collect::<Vec<_>>();
         sensors.push(Sensor {
             sx: line[0],
@@ -93,29 +66,23 @@ fn main() {
     }
 
     // The line to check.
-    const Y: isize = 2000000;
+    const Y: i64 = 2000000;
 
     // Collect all the ranges in line Y where no beacons could be.
-    let mut ranges: VecDeque<(isize, isize)> = VecDeque::new();
-    for s in &sensors {
-        let r = s.covered_in_line(Y);
-        // Only collect non-empty ranges, ofc.
-        if let Some(sr) = r {
-            ranges.push_back(sr);
-        }
-    }
-
-    // Next up, sort + collapse the ranges.
-    collapse_ranges(&mut ranges);
+    // The IntervalSet takes care of merging them into the minimal set of ranges.
+    let ranges: IntervalSet = sensors
+        .iter()
+        .filter_map(|s| s.covered_in_line(Y))
+        .collect();
 
     // Collect all beacons. (there are more sensors than beacons)
-    let all_beacons: HashSet<(isize, isize)> = sensors.iter().map(|e| (e.bx, e.by)).collect();
+    let all_beacons: HashSet<(i64, i64)> = sensors.iter().map(|e| (e.bx, e.by)).collect();
 
     // Count the number of unique beacons in that line.
-    let beacons_in_line = all_beacons.iter().filter(|e| e.1 == Y).count() as isize;
+    let beacons_in_line = all_beacons.iter().filter(|e| e.1 == Y).count() as i64;
 
     // Calculate the total count within the line's ranges.
-    let count = ranges.iter().fold(0, |acc, e| acc + (e.1 - e.0 + 1));
+    let count = ranges.len() as i64;
 
     println!(
         "No. of spots where no beacon can be: {}",